@@ -9,6 +9,7 @@ import {
 } from "../../scripts/updater/command.ts";
 import { fileExists } from "../../scripts/updater/fs.ts";
 import { updateReadme } from "../../scripts/generatePackageDocs.ts";
+import { buildUpdatePrBody } from "../../scripts/treeupdt/changelog.ts";
 
 type UpdateType = "package" | "flake-input";
 
@@ -59,6 +60,23 @@ async function nixEvalPackageVersion(
   return result.stdout.trim() || "unknown";
 }
 
+async function nixEvalPackageHomepage(
+  name: string,
+  system: string,
+  env: Env,
+): Promise<string | null> {
+  const attr = `.#packages.${system}."${name}".meta.homepage`;
+  const result = await runCapture("nix", ["eval", "--raw", "--impure", attr], { env });
+  if (result.code !== 0) return null;
+  return result.stdout.trim() || null;
+}
+
+function parseGithubRepo(url: string): { owner: string; repo: string } | null {
+  const match = url.match(/github\.com\/([^/]+)\/([^/#?]+)/);
+  if (!match?.[1] || !match[2]) return null;
+  return { owner: match[1], repo: match[2].replace(/\.git$/, "") };
+}
+
 async function readFlakeInputRev(name: string): Promise<string> {
   const lockText = await Deno.readTextFile("flake.lock");
   const lockData: unknown = JSON.parse(lockText);
@@ -318,10 +336,30 @@ async function main(): Promise<void> {
   const prTitle = type === "package"
     ? `${name}: ${currentVersion} -> ${newVersion}`
     : `flake.lock: Update ${name}`;
-  const prBody = type === "package"
+  let prBody = type === "package"
     ? `Automated update of ${name} from ${currentVersion} to ${newVersion}.`
     : `This PR updates the flake input \`${name}\`.\n\n- ${name}: \`${currentVersion}\` → \`${newVersion}\``;
 
+  if (type === "package") {
+    const homepage = await nixEvalPackageHomepage(name, system, env);
+    const repoRef = homepage ? parseGithubRepo(homepage) : null;
+    if (repoRef) {
+      try {
+        prBody = await buildUpdatePrBody({
+          name,
+          owner: repoRef.owner,
+          repo: repoRef.repo,
+          oldVersion: currentVersion,
+          newVersion,
+        });
+      } catch (err) {
+        console.log(
+          `Note: could not fetch release notes: ${err instanceof Error ? err.message : String(err)}`,
+        );
+      }
+    }
+  }
+
   console.log("=== Create/Update PR ===");
   console.log(`branch=${branch}`);
   console.log(`title=${prTitle}`);
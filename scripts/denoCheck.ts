@@ -2,6 +2,7 @@ import "../.github/actions/buildMatrix.ts";
 import "../.github/actions/updateItem.ts";
 
 import "./generatePackageDocs.ts";
+import "./treeupdt/cli.ts";
 
 import "../packages/backlog-md/update.ts";
 import "../packages/catnip/update.ts";
@@ -0,0 +1,57 @@
+import type { Annotation } from "./types.ts";
+
+const markerPattern = /(?:#|\/\/)\s*treeupdt:\s*(.+)$/;
+const directivePattern = /([a-z-]+)(?:=(?:"([^"]*)"|(\S+)))?/g;
+
+/** Parse the directive list after a `treeupdt:` marker. */
+export function parseAnnotation(text: string): Annotation {
+  let ignore: boolean | undefined;
+  let ignoreUntil: string | undefined;
+  let ignoreMajor: boolean | undefined;
+  let reason: string | undefined;
+
+  for (const match of text.matchAll(directivePattern)) {
+    const value = match[2] ?? match[3];
+    switch (match[1]) {
+      case "ignore":
+        ignore = true;
+        break;
+      case "ignore-major":
+        ignoreMajor = true;
+        break;
+      case "ignore-until":
+        if (value !== undefined) ignoreUntil = value;
+        break;
+      case "reason":
+        if (value !== undefined) reason = value;
+        break;
+      default:
+        // Unknown directives are ignored so older binaries tolerate newer files.
+        break;
+    }
+  }
+  return {
+    ...(ignore !== undefined ? { ignore } : {}),
+    ...(ignoreUntil !== undefined ? { ignoreUntil } : {}),
+    ...(ignoreMajor !== undefined ? { ignoreMajor } : {}),
+    ...(reason !== undefined ? { reason } : {}),
+  };
+}
+
+/** Extract a `# treeupdt: ...` / `// treeupdt: ...` annotation from a line. */
+export function annotationFromLine(line: string): Annotation | null {
+  const match = line.match(markerPattern);
+  if (match?.[1] === undefined) return null;
+  const annotation = parseAnnotation(match[1]);
+  return Object.keys(annotation).length > 0 ? annotation : null;
+}
+
+/** Whether an ignore directive is in force; `ignore-until` auto-expires. */
+export function isIgnoreActive(annotation: Annotation, now: Date = new Date()): boolean {
+  if (annotation.ignore === true) return true;
+  if (annotation.ignoreUntil !== undefined) {
+    const until = Date.parse(annotation.ignoreUntil);
+    return Number.isFinite(until) && now.getTime() < until;
+  }
+  return false;
+}
@@ -0,0 +1,326 @@
+import { dirname, join } from "node:path";
+
+import { isRecord } from "../updater/assert.ts";
+import { fnv1a, treeupdtCacheDir } from "./state.ts";
+
+/** One cached value with its bookkeeping; `expiresAt` absent means no TTL. */
+export type CacheEntry = Readonly<{
+  key: string;
+  value: unknown;
+  /** ISO 8601 write timestamp. */
+  storedAt: string;
+  /** ISO 8601 expiry; entries past it are stale but kept until pruned. */
+  expiresAt?: string;
+  /** HTTP validators for conditional revalidation once the entry expires. */
+  etag?: string;
+  lastModified?: string;
+}>;
+
+/** Validators stored alongside a cached value. */
+export type CacheMeta = Readonly<{
+  etag?: string;
+  lastModified?: string;
+}>;
+
+/**
+ * Response cache shared by the sources. Both backends store entries under the
+ * treeupdt cache directory and survive across runs; stale entries are served
+ * only when the caller asks for them explicitly.
+ */
+export interface Cache {
+  get(key: string): Promise<CacheEntry | null>;
+  set(key: string, value: unknown, ttlMs: number | null, meta?: CacheMeta): Promise<void>;
+  /** Store a complete entry verbatim, keeping its timestamps (cache import). */
+  put(entry: CacheEntry): Promise<void>;
+  delete(key: string): Promise<void>;
+  /** Every stored entry, for stats, pruning, and export. */
+  entries(): Promise<CacheEntry[]>;
+}
+
+export function isFresh(entry: CacheEntry, now = Date.now()): boolean {
+  return entry.expiresAt === undefined || Date.parse(entry.expiresAt) > now;
+}
+
+function makeEntry(
+  key: string,
+  value: unknown,
+  ttlMs: number | null,
+  meta: CacheMeta = {},
+): CacheEntry {
+  const now = Date.now();
+  return {
+    key,
+    value,
+    storedAt: new Date(now).toISOString(),
+    ...(ttlMs !== null ? { expiresAt: new Date(now + ttlMs).toISOString() } : {}),
+    ...(meta.etag !== undefined ? { etag: meta.etag } : {}),
+    ...(meta.lastModified !== undefined ? { lastModified: meta.lastModified } : {}),
+  };
+}
+
+/** Shape check for entries read back from disk or an export file. */
+export function parseCacheEntry(data: unknown): CacheEntry | null {
+  if (!isRecord(data)) return null;
+  if (typeof data["key"] !== "string" || typeof data["storedAt"] !== "string") return null;
+  return data as CacheEntry;
+}
+
+const lockRetryMs = 50;
+const lockStealAfterMs = 5_000;
+
+/**
+ * Advisory lock for cache writers sharing a file across processes (parallel
+ * CI jobs with a shared cache). Unlike the update lock this waits instead of
+ * failing, and steals locks older than a few seconds since a crashed writer
+ * would otherwise wedge every job.
+ */
+async function withFileLock<T>(path: string, fn: () => Promise<T>): Promise<T> {
+  const lockPath = `${path}.lock`;
+  await Deno.mkdir(dirname(path), { recursive: true });
+  const deadline = Date.now() + lockStealAfterMs;
+  for (;;) {
+    try {
+      await Deno.writeTextFile(lockPath, `${Deno.pid}\n`, { createNew: true });
+      break;
+    } catch (err) {
+      if (!(err instanceof Deno.errors.AlreadyExists)) throw err;
+      if (Date.now() > deadline) {
+        await Deno.remove(lockPath).catch(() => undefined);
+        continue;
+      }
+      await new Promise((resolve) => setTimeout(resolve, lockRetryMs));
+    }
+  }
+  try {
+    return await fn();
+  } finally {
+    await Deno.remove(lockPath).catch(() => undefined);
+  }
+}
+
+/** Write via temp file + rename so readers never observe a partial JSON. */
+async function writeAtomic(path: string, contents: string): Promise<void> {
+  const tmp = `${path}.${Deno.pid}.tmp`;
+  await Deno.writeTextFile(tmp, contents);
+  await Deno.rename(tmp, path);
+}
+
+/** The original backend: one JSON file per key under `<cache>/http/`. */
+export class FileCache implements Cache {
+  readonly #dir: string;
+
+  constructor(dir = join(treeupdtCacheDir(), "http")) {
+    this.#dir = dir;
+  }
+
+  #path(key: string): string {
+    return join(this.#dir, `${fnv1a(key)}.json`);
+  }
+
+  async get(key: string): Promise<CacheEntry | null> {
+    try {
+      const parsed: unknown = JSON.parse(await Deno.readTextFile(this.#path(key)));
+      const entry = parseCacheEntry(parsed);
+      // Hash collisions are unlikely but cheap to rule out.
+      return entry !== null && entry.key === key ? entry : null;
+    } catch {
+      return null;
+    }
+  }
+
+  async set(key: string, value: unknown, ttlMs: number | null, meta?: CacheMeta): Promise<void> {
+    await Deno.mkdir(this.#dir, { recursive: true });
+    // The rename is atomic and each key has its own file, so no lock needed.
+    await writeAtomic(
+      this.#path(key),
+      `${JSON.stringify(makeEntry(key, value, ttlMs, meta), null, 2)}\n`,
+    );
+  }
+
+  async put(entry: CacheEntry): Promise<void> {
+    await Deno.mkdir(this.#dir, { recursive: true });
+    await writeAtomic(this.#path(entry.key), `${JSON.stringify(entry, null, 2)}\n`);
+  }
+
+  async delete(key: string): Promise<void> {
+    await Deno.remove(this.#path(key)).catch(() => undefined);
+  }
+
+  async entries(): Promise<CacheEntry[]> {
+    const found: CacheEntry[] = [];
+    let listing: AsyncIterable<Deno.DirEntry>;
+    try {
+      listing = Deno.readDir(this.#dir);
+    } catch {
+      return [];
+    }
+    for await (const item of listing) {
+      if (!item.isFile || !item.name.endsWith(".json")) continue;
+      try {
+        const parsed: unknown = JSON.parse(await Deno.readTextFile(join(this.#dir, item.name)));
+        const entry = parseCacheEntry(parsed);
+        if (entry !== null) found.push(entry);
+      } catch {
+        // Skip corrupt files; prune will clear them out eventually.
+      }
+    }
+    return found;
+  }
+}
+
+/**
+ * Single-file backend with an in-memory index: all entries live in one JSON
+ * document that is read once per run and rewritten atomically via a temp
+ * file, which beats thousands of tiny files for large dependency sets.
+ */
+export class IndexedCache implements Cache {
+  readonly #path: string;
+  #loaded: Promise<Map<string, CacheEntry>> | null = null;
+
+  constructor(path = join(treeupdtCacheDir(), "cache.json")) {
+    this.#path = path;
+  }
+
+  #load(): Promise<Map<string, CacheEntry>> {
+    this.#loaded ??= this.#read();
+    return this.#loaded;
+  }
+
+  async #read(): Promise<Map<string, CacheEntry>> {
+    const index = new Map<string, CacheEntry>();
+    try {
+      const parsed: unknown = JSON.parse(await Deno.readTextFile(this.#path));
+      if (Array.isArray(parsed)) {
+        for (const item of parsed) {
+          const entry = parseCacheEntry(item);
+          if (entry !== null) index.set(entry.key, entry);
+        }
+      }
+    } catch {
+      // Missing or corrupt: start empty.
+    }
+    return index;
+  }
+
+  async #flush(index: Map<string, CacheEntry>): Promise<void> {
+    await Deno.mkdir(dirname(this.#path), { recursive: true });
+    await writeAtomic(this.#path, `${JSON.stringify([...index.values()], null, 2)}\n`);
+  }
+
+  /**
+   * Read-modify-write under the advisory lock: the whole document is shared
+   * between processes, so the mutation must apply to the latest on-disk
+   * state or a concurrent writer's entries would be silently dropped.
+   */
+  async #mutate(fn: (index: Map<string, CacheEntry>) => boolean): Promise<void> {
+    await withFileLock(this.#path, async () => {
+      const index = await this.#read();
+      if (fn(index)) {
+        await this.#flush(index);
+      }
+      this.#loaded = Promise.resolve(index);
+    });
+  }
+
+  async get(key: string): Promise<CacheEntry | null> {
+    return (await this.#load()).get(key) ?? null;
+  }
+
+  async set(key: string, value: unknown, ttlMs: number | null, meta?: CacheMeta): Promise<void> {
+    await this.#mutate((index) => {
+      index.set(key, makeEntry(key, value, ttlMs, meta));
+      return true;
+    });
+  }
+
+  async put(entry: CacheEntry): Promise<void> {
+    await this.#mutate((index) => {
+      index.set(entry.key, entry);
+      return true;
+    });
+  }
+
+  async delete(key: string): Promise<void> {
+    await this.#mutate((index) => index.delete(key));
+  }
+
+  async entries(): Promise<CacheEntry[]> {
+    return [...(await this.#load()).values()];
+  }
+}
+
+export type CacheCounters = Readonly<{ hits: number; misses: number }>;
+
+const counters = { hits: 0, misses: 0 };
+let flushRegistered = false;
+
+function countersPath(): string {
+  return join(treeupdtCacheDir(), "cache-stats.json");
+}
+
+/** Persist this run's counters on exit, accumulating across runs. */
+function flushCounters(): void {
+  if (counters.hits === 0 && counters.misses === 0) return;
+  let previous = { hits: 0, misses: 0 };
+  try {
+    const parsed: unknown = JSON.parse(Deno.readTextFileSync(countersPath()));
+    if (
+      isRecord(parsed) && typeof parsed["hits"] === "number" &&
+      typeof parsed["misses"] === "number"
+    ) {
+      previous = { hits: parsed["hits"], misses: parsed["misses"] };
+    }
+  } catch {
+    // First run or corrupt file: start from zero.
+  }
+  try {
+    const merged = {
+      hits: previous.hits + counters.hits,
+      misses: previous.misses + counters.misses,
+    };
+    Deno.writeTextFileSync(countersPath(), `${JSON.stringify(merged)}\n`);
+  } catch {
+    // Counters are best-effort; never fail the run over them.
+  }
+}
+
+/** Count a cache lookup for `cache stats`; flushed when the process exits. */
+export function recordCacheAccess(hit: boolean): void {
+  if (hit) {
+    counters.hits += 1;
+  } else {
+    counters.misses += 1;
+  }
+  if (!flushRegistered) {
+    flushRegistered = true;
+    addEventListener("unload", flushCounters);
+  }
+}
+
+/** Accumulated hit/miss counters from previous runs, or null when absent. */
+export async function loadCacheCounters(): Promise<CacheCounters | null> {
+  try {
+    const parsed: unknown = JSON.parse(await Deno.readTextFile(countersPath()));
+    if (
+      isRecord(parsed) && typeof parsed["hits"] === "number" &&
+      typeof parsed["misses"] === "number"
+    ) {
+      return { hits: parsed["hits"], misses: parsed["misses"] };
+    }
+  } catch {
+    // Fall through.
+  }
+  return null;
+}
+
+export const cacheBackends = ["files", "indexed"] as const;
+
+export type CacheBackend = (typeof cacheBackends)[number];
+
+export function isCacheBackend(value: string): value is CacheBackend {
+  return (cacheBackends as readonly string[]).includes(value);
+}
+
+export function openCache(backend: CacheBackend = "files"): Cache {
+  return backend === "indexed" ? new IndexedCache() : new FileCache();
+}
@@ -0,0 +1,19 @@
+import { warn } from "./log.ts";
+
+/**
+ * Cancellation signal wired to Ctrl-C for long CLI runs. The first SIGINT
+ * aborts the signal so the pipeline can stop scheduling work, flush partial
+ * results, and let `FileTransaction.commit` refuse late writes; a second
+ * SIGINT exits immediately with the conventional 130.
+ */
+export function interruptSignal(): AbortSignal {
+  const controller = new AbortController();
+  Deno.addSignalListener("SIGINT", () => {
+    if (controller.signal.aborted) {
+      Deno.exit(130);
+    }
+    warn("interrupted: finishing in-flight work (Ctrl-C again to exit now)");
+    controller.abort();
+  });
+  return controller.signal;
+}
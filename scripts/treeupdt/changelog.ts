@@ -0,0 +1,130 @@
+import { assertRecord, assertString } from "../updater/assert.ts";
+import { buildGithubHeaders, resolveGithubToken } from "../updater/github.ts";
+import { fetchJson, fetchText } from "./http.ts";
+
+const maxExcerptLength = 4000;
+
+export type ReleaseNotes = Readonly<{
+  tag: string;
+  body: string;
+}>;
+
+/** Tag spellings to try for a plain version string, most common first. */
+export function candidateTags(version: string): string[] {
+  return version.startsWith("v") ? [version] : [`v${version}`, version];
+}
+
+export function githubCompareUrl(
+  owner: string,
+  repo: string,
+  fromTag: string,
+  toTag: string,
+): string {
+  return `https://github.com/${owner}/${repo}/compare/${fromTag}...${toTag}`;
+}
+
+function truncateExcerpt(body: string): string {
+  const trimmed = body.trim();
+  if (trimmed.length <= maxExcerptLength) return trimmed;
+  return `${trimmed.slice(0, maxExcerptLength)}\n\n*(release notes truncated)*`;
+}
+
+export async function fetchGithubReleaseNotes(
+  owner: string,
+  repo: string,
+  tags: readonly string[],
+): Promise<ReleaseNotes | null> {
+  const headers = buildGithubHeaders(resolveGithubToken());
+  for (const tag of tags) {
+    const url = `https://api.github.com/repos/${owner}/${repo}/releases/tags/${tag}`;
+    try {
+      const data = await fetchJson(url, { headers });
+      assertRecord(data, `GitHub release ${owner}/${repo}@${tag}`);
+      const body = data["body"];
+      if (typeof body !== "string" || !body.trim()) continue;
+      return { tag, body };
+    } catch {
+      // Missing tag or API error; try the next spelling.
+    }
+  }
+  return null;
+}
+
+/** Extract the section for `version` from a Keep-a-Changelog style document. */
+export function extractChangelogSection(content: string, version: string): string | null {
+  const lines = content.split("\n");
+  const headingIndex = lines.findIndex(
+    (line) => /^#{1,3}\s/.test(line) && line.includes(version),
+  );
+  if (headingIndex === -1) return null;
+
+  const headingLine = lines[headingIndex];
+  assertString(headingLine, "extractChangelogSection: heading");
+  const level = headingLine.match(/^#+/)?.[0].length ?? 2;
+
+  const section: string[] = [];
+  for (let i = headingIndex + 1; i < lines.length; i += 1) {
+    const line = lines[i] ?? "";
+    const match = line.match(/^(#+)\s/);
+    if (match?.[1] && match[1].length <= level) break;
+    section.push(line);
+  }
+
+  const text = section.join("\n").trim();
+  return text ? text : null;
+}
+
+export async function fetchChangelogExcerpt(
+  owner: string,
+  repo: string,
+  tag: string,
+  version: string,
+): Promise<string | null> {
+  const url = `https://raw.githubusercontent.com/${owner}/${repo}/${tag}/CHANGELOG.md`;
+  try {
+    const content = await fetchText(url);
+    return extractChangelogSection(content, version);
+  } catch {
+    return null;
+  }
+}
+
+export type PrBodyOptions = Readonly<{
+  name: string;
+  owner: string;
+  repo: string;
+  oldVersion: string;
+  newVersion: string;
+}>;
+
+/**
+ * Build a PR description for a version bump: a summary line, the GitHub
+ * release body (or CHANGELOG.md section) for the new version when one exists,
+ * and a compare link between the two tags.
+ */
+export async function buildUpdatePrBody(opts: PrBodyOptions): Promise<string> {
+  const newTags = candidateTags(opts.newVersion);
+  const notes = await fetchGithubReleaseNotes(opts.owner, opts.repo, newTags);
+
+  const newTag = notes?.tag ?? newTags[0] ?? opts.newVersion;
+  const oldTag = opts.oldVersion.startsWith("v") || !newTag.startsWith("v")
+    ? opts.oldVersion
+    : `v${opts.oldVersion}`;
+
+  const sections: string[] = [
+    `Automated update of ${opts.name} from ${opts.oldVersion} to ${opts.newVersion}.`,
+  ];
+
+  const excerpt = notes
+    ? notes.body
+    : await fetchChangelogExcerpt(opts.owner, opts.repo, newTag, opts.newVersion);
+  if (excerpt) {
+    sections.push(`## Release notes (${newTag})\n\n${truncateExcerpt(excerpt)}`);
+  }
+
+  sections.push(
+    `**Full changelog**: ${githubCompareUrl(opts.owner, opts.repo, oldTag, newTag)}`,
+  );
+
+  return sections.join("\n\n");
+}
@@ -0,0 +1,422 @@
+import { pMap } from "../updater/pMap.ts";
+import { isIgnoreActive } from "./annotations.ts";
+import {
+  applyProfile,
+  type Config,
+  ConfigTree,
+  effectiveMinimumReleaseAge,
+  effectivePinVersion,
+  effectivePreferredSources,
+  effectiveSchedule,
+  effectiveStrategy,
+  loadConfig,
+  matchGroup,
+} from "./config.ts";
+import { fetchEolCycles, findCycle } from "./eol.ts";
+import { allowedByLists, emptyFilter, type Filter, matchesFilter, mergeFilters } from "./filter.ts";
+import { Progress } from "./progress.ts";
+import { filterByReleaseAge, parseDuration } from "./releaseAge.ts";
+import { scheduleOpen } from "./schedule.ts";
+import { extractVersion } from "./versionTemplate.ts";
+import { classifyChange, isNewerVersion } from "./semverRange.ts";
+import { classifyError } from "./errors.ts";
+import { type EventListener, nullListener } from "./events.ts";
+import { debug, trace } from "./log.ts";
+import { loadPlugins } from "./plugins.ts";
+import { defaultScannerRegistry, scanTree } from "./scan.ts";
+import { type PathSpec, selectPackages } from "./select.ts";
+import { Semaphore } from "./semaphore.ts";
+import { defaultSourceRegistry, type SourceRegistry, UnknownPackageError } from "./sources.ts";
+import { selectTarget } from "./strategy.ts";
+import type {
+  AlternateResult,
+  KnownSourceType,
+  Package,
+  SourceHint,
+  SourceType,
+  Strategy,
+  UpdateEntry,
+  UpdateReport,
+} from "./types.ts";
+
+export type CheckOptions = Readonly<{
+  /** Overall concurrency across packages (`--jobs`). */
+  jobs?: number;
+  sources?: SourceRegistry;
+  /** Loaded from `.treeupdt.json` in the root when not provided. */
+  config?: Config;
+  /** Named config profile to overlay (`--profile`). */
+  profile?: string;
+  /** Skip packages whose schedule window is closed (`--respect-schedule`). */
+  respectSchedule?: boolean;
+  /** Serve from cache only, never touching the network (`--offline`). */
+  offline?: boolean;
+  /** Positional path/package selectors; empty means check everything. */
+  selectors?: readonly PathSpec[];
+  /** CLI-level filter, combined with `global.filters` from the config. */
+  filter?: Filter;
+  /** Show a progress line on stderr while checking. */
+  progress?: boolean;
+  /** Receives pipeline progress events; see events.ts. */
+  onEvent?: EventListener;
+  /** Cancellation: stops scheduling checks; done entries are still reported. */
+  signal?: AbortSignal;
+}>;
+
+/** Per-source concurrency caps, conservative for rate-limited APIs. */
+const sourceConcurrency: Readonly<Record<KnownSourceType, number>> = {
+  github: 4,
+  npm: 8,
+  crates: 8,
+  goproxy: 8,
+};
+
+/** Cap for externally registered sources without a configured concurrency. */
+const customSourceConcurrency = 4;
+
+export const defaultJobs = 8;
+
+/** Registries know about yanks and deprecations, so they beat GitHub releases. */
+export const defaultSourcePriority: readonly string[] = ["npm", "crates", "goproxy", "github"];
+
+/**
+ * Collapse a package's per-source entries into one: the highest-priority
+ * source that answered without error wins, and the rest are kept as
+ * alternates so conflicting results stay visible.
+ */
+function reconcile(
+  entries: readonly UpdateEntry[],
+  priority: readonly string[],
+): UpdateEntry[] {
+  if (entries.length <= 1) return [...entries];
+  const rank = (entry: UpdateEntry): number => {
+    const index = priority.indexOf(entry.source);
+    return index === -1 ? priority.length : index;
+  };
+  const sorted = [...entries].sort((a, b) => rank(a) - rank(b));
+  const primary = sorted.find((entry) => entry.error === undefined) ?? sorted[0];
+  if (!primary) return [];
+  primary.alternates = sorted.filter((entry) => entry !== primary).map(toAlternate);
+  return [primary];
+}
+
+function toAlternate(entry: UpdateEntry): AlternateResult {
+  return {
+    source: entry.source,
+    ...(entry.identifier !== undefined ? { identifier: entry.identifier } : {}),
+    ...(entry.latest !== undefined ? { latest: entry.latest } : {}),
+    ...(entry.error !== undefined ? { error: entry.error } : {}),
+    ...(entry.errorCode !== undefined ? { errorCode: entry.errorCode } : {}),
+  };
+}
+
+/** Warn when a pinned runtime cycle (go directive, Node engines) is EOL. */
+async function checkEol(pkg: Package, product: string): Promise<UpdateEntry> {
+  const entry: UpdateEntry = {
+    id: pkg.id,
+    name: pkg.name,
+    file: pkg.file,
+    fileType: pkg.fileType,
+    current: pkg.version,
+    source: "endoflife",
+    ...(pkg.span !== undefined ? { span: pkg.span } : {}),
+  };
+
+  try {
+    const cycles = await fetchEolCycles(product);
+    const cycle = findCycle(cycles, pkg.version.replace(/^[\^~=<>\s]+/, ""));
+    if (!cycle) {
+      entry.error = `No endoflife.date cycle matches ${pkg.version}`;
+      return entry;
+    }
+    entry.eol = cycle.eol;
+    if (cycle.eolDate !== null) {
+      entry.eolDate = cycle.eolDate;
+    }
+  } catch (err) {
+    entry.error = err instanceof Error ? err.message : String(err);
+  }
+  return entry;
+}
+
+async function checkPackage(
+  pkg: Package,
+  strategy: Strategy,
+  pinVersion: string | undefined,
+  preferredSources: readonly string[] | undefined,
+  extractPattern: string | undefined,
+  minimumReleaseAge: string | null,
+  sourcePriority: readonly string[],
+  sources: SourceRegistry,
+  limiters: ReadonlyMap<SourceType, Semaphore>,
+): Promise<UpdateEntry[]> {
+  // A pin fixes the wanted version, so there is nothing to ask a source;
+  // the only interesting outcome is the manifest drifting away from it.
+  if (pinVersion !== undefined) {
+    return [{
+      id: pkg.id,
+      name: pkg.name,
+      file: pkg.file,
+      fileType: pkg.fileType,
+      current: pkg.version,
+      source: pkg.sourceHints[0]?.source ?? "none",
+      latest: pinVersion,
+      updateAvailable: false,
+      pinVersion,
+      ...(pkg.version !== pinVersion ? { pinDrift: true } : {}),
+      ...(pkg.span !== undefined ? { span: pkg.span } : {}),
+    }];
+  }
+  if (pkg.annotation !== undefined && isIgnoreActive(pkg.annotation)) {
+    return [{
+      id: pkg.id,
+      name: pkg.name,
+      file: pkg.file,
+      fileType: pkg.fileType,
+      current: pkg.version,
+      source: pkg.sourceHints[0]?.source ?? "none",
+      ignored: true,
+      ...(pkg.annotation.reason !== undefined ? { ignoreReason: pkg.annotation.reason } : {}),
+      ...(pkg.span !== undefined ? { span: pkg.span } : {}),
+    }];
+  }
+  // `ignore-major` tightens the strategy without touching the config.
+  if (pkg.annotation?.ignoreMajor === true && strategy === "latest") {
+    strategy = "conservative";
+  }
+  if (pkg.eolProduct !== undefined) {
+    return [await checkEol(pkg, pkg.eolProduct)];
+  }
+  const checkHint = async (hint: SourceHint): Promise<UpdateEntry> => {
+    const entry: UpdateEntry = {
+      id: pkg.id,
+      name: pkg.name,
+      file: pkg.file,
+      fileType: pkg.fileType,
+      current: pkg.version,
+      source: hint.source,
+      identifier: hint.identifier,
+      ...(pkg.span !== undefined ? { span: pkg.span } : {}),
+    };
+
+    const source = sources.get(hint.source);
+    if (!source) {
+      entry.error = `No source registered for ${hint.source}`;
+      return entry;
+    }
+
+    try {
+      const limiter = limiters.get(hint.source);
+      let versions = limiter
+        ? await limiter.with(() => source.listVersions(hint.identifier))
+        : await source.listVersions(hint.identifier);
+      // Tags that don't match `extract-version` are not releases at all.
+      if (extractPattern !== undefined) {
+        versions = versions.flatMap((v) => {
+          const extracted = extractVersion(extractPattern, v.version);
+          return extracted === null ? [] : [{ ...v, version: extracted }];
+        });
+      }
+      // Releases inside the cooldown window are not offered as candidates;
+      // the full list still answers "what is my current version's status".
+      const candidates = minimumReleaseAge !== null
+        ? filterByReleaseAge(versions, parseDuration(minimumReleaseAge))
+        : versions;
+      const newest = candidates.find((v) => !v.prerelease) ?? candidates[0];
+      if (!newest) {
+        if (versions.length > 0) {
+          entry.updateAvailable = false;
+          return entry;
+        }
+        entry.error = `No versions found for ${hint.identifier}`;
+        return entry;
+      }
+      const current = versions.find(
+        (v) => v.version === pkg.version || v.version === pkg.version.replace(/^v/, ""),
+      );
+      if (current?.yanked === true) {
+        entry.currentVersionStatus = "yanked";
+      } else if (current?.deprecated !== undefined) {
+        entry.currentVersionStatus = "deprecated";
+        entry.deprecationMessage = current.deprecated;
+      } else if (current !== undefined) {
+        entry.currentVersionStatus = "ok";
+      }
+
+      entry.releasesBehind = candidates.filter(
+        (v) => v.prerelease !== true && isNewerVersion(pkg.version, v.version),
+      ).length;
+      if (current?.publishedAt !== undefined) {
+        entry.currentPublishedAt = current.publishedAt;
+      }
+
+      entry.strategy = strategy;
+      const target = selectTarget(pkg.version, candidates, strategy);
+      if (target) {
+        entry.latest = target.version;
+        entry.updateAvailable = true;
+        if (current?.license !== undefined) {
+          entry.currentLicense = current.license;
+        }
+        if (target.license !== undefined) {
+          entry.latestLicense = target.license;
+        }
+        const level = classifyChange(pkg.version, target.version);
+        if (level !== null) {
+          entry.semverLevel = level;
+        }
+        if (target.publishedAt !== undefined) {
+          entry.latestPublishedAt = target.publishedAt;
+        }
+      } else {
+        entry.latest = newest.version;
+        entry.updateAvailable = false;
+        if (strategy !== "latest" && isNewerVersion(pkg.version, newest.version)) {
+          entry.blockedByStrategy = true;
+        }
+      }
+    } catch (err) {
+      entry.error = err instanceof Error ? err.message : String(err);
+      entry.errorCode = classifyError(err);
+      if (err instanceof UnknownPackageError) {
+        entry.unknownPackage = true;
+      }
+    }
+    return entry;
+  };
+
+  // A `preferred-source` chain is sequential by design: later sources are
+  // only consulted when the ones before them fail or have no releases.
+  const chain = preferredSources
+    ?.map((sourceType) => pkg.sourceHints.find((hint) => hint.source === sourceType))
+    .filter((hint): hint is SourceHint => hint !== undefined);
+  if (chain !== undefined && chain.length > 0) {
+    const failures: UpdateEntry[] = [];
+    for (const hint of chain) {
+      const entry = await checkHint(hint);
+      if (entry.error === undefined) {
+        if (failures.length > 0) {
+          entry.alternates = failures.map(toAlternate);
+        }
+        return [entry];
+      }
+      failures.push(entry);
+    }
+    return reconcile(failures, sourcePriority);
+  }
+
+  const perSource = await Promise.all(pkg.sourceHints.map(checkHint));
+  return reconcile(perSource, sourcePriority);
+}
+
+/**
+ * Scan a tree and query each package's sources for newer versions. Checks run
+ * concurrently up to `jobs`, with per-source caps so bursts stay inside API
+ * rate limits.
+ */
+export async function runCheckPipeline(
+  root: string,
+  opts: CheckOptions = {},
+): Promise<UpdateReport> {
+  let config = opts.config ?? await loadConfig(root);
+  if (opts.profile !== undefined) {
+    config = applyProfile(config, opts.profile);
+  }
+  const emit = opts.onEvent ?? nullListener;
+  emit({ kind: "scan-started", root });
+  const filter = mergeFilters(config.global.filters ?? emptyFilter, opts.filter ?? emptyFilter);
+  const plugins = await loadPlugins(config);
+  const scanners = defaultScannerRegistry();
+  for (const scanner of plugins.scanners) {
+    scanners.register(scanner);
+  }
+  const packages = selectPackages(
+    await scanTree(root, scanners, config.global.excludePaths ?? []),
+    opts.selectors ?? [],
+  ).filter((pkg) =>
+    matchesFilter(pkg, filter) &&
+    allowedByLists(pkg.name, config.global.onlyPackages ?? [], config.global.denyPackages ?? [])
+  );
+  const sources = opts.sources ??
+    defaultSourceRegistry(config, opts.offline !== undefined ? { offline: opts.offline } : {});
+  if (opts.sources === undefined) {
+    for (const source of plugins.sources) {
+      sources.register(source);
+    }
+  }
+
+  debug("scan complete", { packages: packages.length, root });
+  for (const pkg of packages) {
+    emit({ kind: "package-found", package: pkg });
+  }
+
+  const sourcePriority = config.global.sourcePriority ?? defaultSourcePriority;
+
+  const limiters = new Map<SourceType, Semaphore>();
+  for (const [type, permits] of Object.entries(sourceConcurrency)) {
+    const configured = config.sources[type]?.concurrency;
+    limiters.set(type as SourceType, new Semaphore(configured ?? permits));
+  }
+  for (const type of sources.types) {
+    if (!limiters.has(type)) {
+      const configured = config.sources[type]?.concurrency;
+      limiters.set(type, new Semaphore(configured ?? customSourceConcurrency));
+    }
+  }
+
+  const configTree = new ConfigTree(root, config);
+  const progress = new Progress(packages.length, {
+    enabled: opts.progress ?? false,
+  });
+  const nested = await pMap(
+    packages,
+    async (pkg) => {
+      // Cancelled: skip the remaining packages but keep finished entries.
+      if (opts.signal?.aborted === true) {
+        progress.advance(pkg.name);
+        return [];
+      }
+      trace("checking package", { name: pkg.name, file: pkg.file });
+      emit({ kind: "check-started", name: pkg.name, file: pkg.file });
+      const pkgConfig = await configTree.forFile(pkg.file);
+      const group = matchGroup(
+        pkgConfig,
+        pkg.name,
+        pkg.file,
+        pkg.sourceHints.map((hint) => hint.source),
+      );
+      if (opts.respectSchedule === true) {
+        const schedule = effectiveSchedule(pkgConfig, pkg.name, group);
+        if (schedule !== undefined && !scheduleOpen(schedule)) {
+          progress.advance(pkg.name);
+          return [];
+        }
+      }
+      const entries = await checkPackage(
+        pkg,
+        effectiveStrategy(pkgConfig, pkg.name, pkg.fileType, group),
+        effectivePinVersion(pkgConfig, pkg.name),
+        effectivePreferredSources(pkgConfig, pkg.name),
+        pkgConfig.packages[pkg.name]?.extractVersion,
+        effectiveMinimumReleaseAge(pkgConfig, pkg.name),
+        sourcePriority,
+        sources,
+        limiters,
+      );
+      if (group !== undefined) {
+        for (const entry of entries) entry.group = group;
+      }
+      for (const entry of entries) {
+        if (entry.error !== undefined) {
+          emit({ kind: "error", name: entry.name, file: entry.file, message: entry.error });
+        }
+      }
+      emit({ kind: "check-finished", name: pkg.name, file: pkg.file, entries });
+      progress.advance(pkg.name);
+      return entries;
+    },
+    { concurrency: Math.min(opts.jobs ?? defaultJobs, Math.max(packages.length, 1)) },
+  );
+  progress.finish();
+  return { entries: nested.flat() };
+}
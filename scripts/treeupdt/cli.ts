@@ -0,0 +1,214 @@
+import { runCache } from "./commands/cache.ts";
+import { runComplete, runCompletions } from "./commands/completions.ts";
+import { configureLogging, error, type LogFormat, logFormats } from "./log.ts";
+import { runChangelog } from "./commands/changelog.ts";
+import { runCheck } from "./commands/check.ts";
+import { runConfig } from "./commands/config.ts";
+import { runDiff } from "./commands/diff.ts";
+import { runExplain } from "./commands/explain.ts";
+import { runHistory } from "./commands/history.ts";
+import { runIgnore } from "./commands/ignore.ts";
+import { runInit } from "./commands/init.ts";
+import { runPin, runUnpin } from "./commands/pin.ts";
+import { runOutdated } from "./commands/outdated.ts";
+import { runAnnotate } from "./commands/annotate.ts";
+import { runBadge } from "./commands/badge.ts";
+import { runApply, runPlan } from "./commands/plan.ts";
+import { runReport } from "./commands/report.ts";
+import { runSbom } from "./commands/sbom.ts";
+import { runScan } from "./commands/scan.ts";
+import { runSearch } from "./commands/search.ts";
+import { runSelfUpdate } from "./commands/selfUpdate.ts";
+import { runServe } from "./commands/serve.ts";
+import { runSnapshot, runVerify } from "./commands/snapshot.ts";
+import { runStats } from "./commands/stats.ts";
+import { runTree } from "./commands/tree.ts";
+import { runUpdate } from "./commands/update.ts";
+
+function printUsage(): void {
+  console.log(`treeupdt - dependency tree updater
+
+Usage: treeupdt [-v|-vv] [--log-format text|json] <command> [args]
+
+Commands:
+  scan [path[:package]...]                       List packages found in a tree
+  check [path[:package]...] [--jobs N]           Report available updates
+  outdated [path[:package]...] [--jobs N]        Aligned table of packages with newer releases
+  tree [--format text|dot] [--check]             Hierarchy of managed files, sections, packages
+  diff <old.json> <new.json>                     Compare two scan snapshots
+  diff --against <git-ref>                       Compare the tree against a git ref
+  snapshot                                       Record all packages in treeupdt.lock
+  verify                                         Fail when the tree drifts from the snapshot
+  verify --hashes                                Re-derive fetcher hashes and report mismatches
+  report [--format md|html|json] [--out file]    Freshness report with per-ecosystem summaries
+  stats [--jobs N]                               Dependency hygiene metrics for the tree
+  sbom [--format cyclonedx|spdx] [--out file]    Software bill of materials from a scan
+  badge [--out badge.svg] [--json]               SVG badge with the outdated count
+  explain <path>:<package>                       Walk through one version-selection decision
+  changelog <package> [--from v] [--to v|latest] Release notes for the intervening versions
+  search <source> <name>                         List a package's versions from one source
+  update <file> <package> <version> [--no-sync]  Apply a version bump to a manifest
+  update --enforce-pins                          Rewrite drifted packages back to their pins
+  plan [--out plan.json]                         Describe every proposed edit as JSON
+  apply <plan.json> [--no-sync]                  Execute a previously produced plan
+  history [--package X]                          Journal of updates treeupdt applied
+  config validate                                Check config files for problems
+  config schema                                  Emit the config JSON Schema
+  config import --from <file>                    Translate a Renovate or Dependabot config
+  config get <key.path>                          Print one config value
+  config set <key.path> <value>                  Write one config value
+  config migrate                                 Rewrite deprecated config keys
+  cache stats                                    Show response cache size and hit rates
+  cache prune [--older-than 7d] [--max-size N]   Remove expired and old cache entries
+  cache export <file>                            Write the cache to a portable JSON file
+  cache import <file>                            Restore entries from a cache export
+  serve --stdio                                  JSON-RPC server for editor integrations
+  serve --listen host:port                       REST API (/scan /check /report /badge)
+  pin <package> <version>                        Pin a package in .treeupdt.json
+  unpin <package>                                Remove a pin
+  ignore <path-spec> [--until DATE] [--config]   Annotate a package as ignored
+  annotate --ignore --name-pattern <glob>        Rewrite annotations in bulk
+  init ci --github|--gitlab                      Scaffold a scheduled update workflow
+  completions bash|zsh|fish|powershell           Emit a shell completion script
+  self-update [--check]                          Update the treeupdt binary from releases
+  help                                           Show this help`);
+}
+
+/** Strip global logging flags, which may appear before the command. */
+function parseGlobalFlags(args: readonly string[]): string[] {
+  let verbosity = 0;
+  let format: LogFormat = "text";
+  const rest: string[] = [];
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "-v") {
+      verbosity += 1;
+    } else if (arg === "-vv") {
+      verbosity += 2;
+    } else if (arg === "--log-format") {
+      const value = args[i + 1];
+      if (value === undefined || !(logFormats as readonly string[]).includes(value)) {
+        throw new Error(`Invalid --log-format value: ${value ?? "<missing>"} (expected text|json)`);
+      }
+      format = value as LogFormat;
+      i += 1;
+    } else if (arg !== undefined) {
+      rest.push(arg);
+    }
+  }
+  configureLogging(verbosity, format);
+  return rest;
+}
+
+async function main(): Promise<void> {
+  const [command, ...rest] = parseGlobalFlags(Deno.args);
+
+  switch (command) {
+    case "scan":
+      await runScan(rest);
+      break;
+    case "check":
+      await runCheck(rest);
+      break;
+    case "outdated":
+      await runOutdated(rest);
+      break;
+    case "tree":
+      await runTree(rest);
+      break;
+    case "diff":
+      await runDiff(rest);
+      break;
+    case "snapshot":
+      await runSnapshot(rest);
+      break;
+    case "verify":
+      await runVerify(rest);
+      break;
+    case "report":
+      await runReport(rest);
+      break;
+    case "stats":
+      await runStats(rest);
+      break;
+    case "sbom":
+      await runSbom(rest);
+      break;
+    case "badge":
+      await runBadge(rest);
+      break;
+    case "explain":
+      await runExplain(rest);
+      break;
+    case "changelog":
+      await runChangelog(rest);
+      break;
+    case "search":
+      await runSearch(rest);
+      break;
+    case "update":
+      await runUpdate(rest);
+      break;
+    case "plan":
+      await runPlan(rest);
+      break;
+    case "apply":
+      await runApply(rest);
+      break;
+    case "history":
+      await runHistory(rest);
+      break;
+    case "config":
+      await runConfig(rest);
+      break;
+    case "cache":
+      await runCache(rest);
+      break;
+    case "serve":
+      await runServe(rest);
+      break;
+    case "pin":
+      await runPin(rest);
+      break;
+    case "unpin":
+      await runUnpin(rest);
+      break;
+    case "ignore":
+      await runIgnore(rest);
+      break;
+    case "annotate":
+      await runAnnotate(rest);
+      break;
+    case "init":
+      await runInit(rest);
+      break;
+    case "completions":
+      runCompletions(rest);
+      break;
+    case "self-update":
+      await runSelfUpdate(rest);
+      break;
+    // Hidden: dynamic completion callback used by the generated scripts.
+    case "__complete":
+      await runComplete(rest);
+      break;
+    case undefined:
+    case "help":
+    case "--help":
+      printUsage();
+      break;
+    default:
+      error(`Unknown command: ${command}`);
+      printUsage();
+      Deno.exit(2);
+  }
+}
+
+if (import.meta.main) {
+  try {
+    await main();
+  } catch (err) {
+    error(err instanceof Error ? err.message : String(err));
+    Deno.exit(1);
+  }
+}
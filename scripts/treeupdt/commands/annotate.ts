@@ -0,0 +1,136 @@
+import { loadConfig } from "../config.ts";
+import { matchGlob } from "../glob.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { Package } from "../types.ts";
+
+const usageText =
+  "Usage: treeupdt annotate --ignore|--ignore-major|--until DATE|--remove " +
+  "[--reason text] --name-pattern <glob> [--type <file-type>]";
+
+/** Strips an existing `# treeupdt:` / `// treeupdt:` marker from a line. */
+const markerSuffix = /\s*(?:#|\/\/)\s*treeupdt:.*$/;
+
+type Directive = Readonly<{
+  kind: "ignore" | "ignore-major" | "until" | "remove";
+  until?: string;
+  reason?: string;
+}>;
+
+function directiveText(directive: Directive): string {
+  const parts = [
+    directive.kind === "until" ? `ignore-until=${directive.until}` : directive.kind,
+  ];
+  if (directive.reason !== undefined) {
+    parts.push(`reason="${directive.reason.replaceAll('"', "'")}"`);
+  }
+  return `treeupdt: ${parts.join(" ")}`;
+}
+
+function rewriteLine(line: string, pkg: Package, directive: Directive): string {
+  const bare = line.replace(markerSuffix, "");
+  if (directive.kind === "remove") return bare;
+  const token = pkg.fileType === "go" ? "//" : "#";
+  return `${bare} ${token} ${directiveText(directive)}`;
+}
+
+/**
+ * `treeupdt annotate`: add, replace, or remove inline annotations across
+ * every manifest line matching the filters, using the scanners' span data,
+ * so a policy change doesn't mean editing dozens of files by hand. Only the
+ * annotation grammar is supported (ignore / ignore-major / ignore-until);
+ * per-package strategies are config, not annotations -- use `config set`.
+ */
+export async function runAnnotate(args: readonly string[]): Promise<void> {
+  let kind: Directive["kind"] | undefined;
+  let until: string | undefined;
+  let reason: string | undefined;
+  const namePatterns: string[] = [];
+  const fileTypes: string[] = [];
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--ignore" || arg === "--ignore-major" || arg === "--remove") {
+      if (kind !== undefined) throw new Error(usageText);
+      kind = arg.slice(2) as Directive["kind"];
+    } else if (arg === "--until") {
+      if (kind !== undefined) throw new Error(usageText);
+      kind = "until";
+      until = args[i + 1];
+      if (until === undefined || !Number.isFinite(Date.parse(until))) {
+        throw new Error(`Invalid --until date: ${until ?? "<missing>"}`);
+      }
+      i += 1;
+    } else if (arg === "--reason") {
+      reason = args[i + 1];
+      if (reason === undefined) throw new Error(usageText);
+      i += 1;
+    } else if (arg === "--name-pattern") {
+      const pattern = args[i + 1];
+      if (pattern === undefined) throw new Error(usageText);
+      namePatterns.push(pattern);
+      i += 1;
+    } else if (arg === "--type") {
+      const fileType = args[i + 1];
+      if (fileType === undefined) throw new Error(usageText);
+      fileTypes.push(fileType);
+      i += 1;
+    } else {
+      throw new Error(usageText);
+    }
+  }
+  if (kind === undefined || namePatterns.length === 0) throw new Error(usageText);
+  const directive: Directive = {
+    kind,
+    ...(until !== undefined ? { until } : {}),
+    ...(reason !== undefined ? { reason } : {}),
+  };
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const matches = (await scanTree(".", registry, config.global.excludePaths ?? []))
+    .filter((pkg) =>
+      namePatterns.some((pattern) =>
+        matchGlob(pattern, pkg.name) || matchGlob(pattern, pkg.id.name)
+      ) &&
+      (fileTypes.length === 0 || fileTypes.includes(pkg.fileType))
+    );
+  if (matches.length === 0) {
+    throw new Error(`No scanned package matches ${namePatterns.join(", ")}`);
+  }
+
+  const byFile = new Map<string, Package[]>();
+  let skipped = 0;
+  for (const pkg of matches) {
+    // JSON manifests can't carry comments; `ignore --config` covers those.
+    if (pkg.fileType === "npm" || pkg.span === undefined) {
+      console.log(`${pkg.name} (${pkg.file}): no annotatable line; skipped`);
+      skipped += 1;
+      continue;
+    }
+    byFile.set(pkg.file, [...(byFile.get(pkg.file) ?? []), pkg]);
+  }
+
+  let annotated = 0;
+  for (const [file, filePackages] of byFile) {
+    const lines = (await Deno.readTextFile(file)).split("\n");
+    for (const pkg of filePackages) {
+      const index = (pkg.span?.line ?? 0) - 1;
+      const line = lines[index];
+      if (line === undefined) continue;
+      lines[index] = rewriteLine(line, pkg, directive);
+      console.log(`${pkg.name}: ${file}:${index + 1}`);
+      annotated += 1;
+    }
+    const transaction = new FileTransaction();
+    transaction.stage(file, lines.join("\n"));
+    await transaction.commit();
+  }
+  console.log(
+    `${annotated} line${annotated === 1 ? "" : "s"} rewritten in ${byFile.size} ` +
+      `file${byFile.size === 1 ? "" : "s"}${skipped > 0 ? `, ${skipped} skipped` : ""}`,
+  );
+}
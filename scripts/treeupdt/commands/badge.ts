@@ -0,0 +1,42 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { badgeData, renderBadgeSvg, shieldsJson } from "../output/badge.ts";
+import { isStderrTerminal } from "../progress.ts";
+
+/**
+ * `treeupdt badge [--out badge.svg] [--json]`: an SVG badge (or, with
+ * `--json`, a shields.io endpoint payload) saying how many dependencies are
+ * outdated, for embedding in READMEs. The same payload is served by the
+ * `badge` method in serve mode.
+ */
+export async function runBadge(args: readonly string[]): Promise<void> {
+  let out: string | undefined;
+  let json = false;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--out") {
+      out = args[i + 1];
+      if (out === undefined) throw new Error("Missing value for --out");
+      i += 1;
+    } else if (args[i] === "--json") {
+      json = true;
+    } else {
+      throw new Error("Usage: treeupdt badge [--out badge.svg] [--json]");
+    }
+  }
+
+  const report = await runCheckPipeline(".", {
+    progress: isStderrTerminal(),
+    signal: interruptSignal(),
+  });
+  const data = badgeData(report.entries);
+  const rendered = json
+    ? `${JSON.stringify(shieldsJson(data), null, 2)}\n`
+    : renderBadgeSvg(data);
+
+  if (out !== undefined) {
+    await Deno.writeTextFile(out, rendered);
+    console.log(`Wrote badge (${data.message}) to ${out}`);
+  } else {
+    console.log(rendered.trimEnd());
+  }
+}
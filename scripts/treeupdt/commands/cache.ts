@@ -0,0 +1,211 @@
+import { isRecord } from "../../updater/assert.ts";
+import {
+  type Cache,
+  type CacheEntry,
+  isFresh,
+  loadCacheCounters,
+  openCache,
+  parseCacheEntry,
+} from "../cache.ts";
+import { loadConfig } from "../config.ts";
+import { parseDuration } from "../releaseAge.ts";
+
+/** Format marker in export files, so stray JSON isn't imported by accident. */
+const exportVersion = 1;
+
+function formatBytes(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KiB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MiB`;
+}
+
+function entrySize(entry: CacheEntry): number {
+  return JSON.stringify(entry).length;
+}
+
+/** The source a key belongs to (`npm:left-pad` -> `npm`). */
+function keySource(key: string): string {
+  const colon = key.indexOf(":");
+  return colon === -1 ? "other" : key.slice(0, colon);
+}
+
+export async function runCacheStats(): Promise<void> {
+  const config = await loadConfig(".");
+  const cache = openCache(config.global.cacheBackend);
+  const entries = await cache.entries();
+
+  if (entries.length === 0) {
+    console.log("Cache is empty");
+    return;
+  }
+
+  let totalSize = 0;
+  const bySource = new Map<string, { count: number; size: number }>();
+  let oldest = entries[0] as CacheEntry;
+  let newest = entries[0] as CacheEntry;
+  for (const entry of entries) {
+    const size = entrySize(entry);
+    totalSize += size;
+    const source = keySource(entry.key);
+    const bucket = bySource.get(source) ?? { count: 0, size: 0 };
+    bucket.count += 1;
+    bucket.size += size;
+    bySource.set(source, bucket);
+    if (entry.storedAt < oldest.storedAt) oldest = entry;
+    if (entry.storedAt > newest.storedAt) newest = entry;
+  }
+
+  console.log(`${entries.length} entries, ${formatBytes(totalSize)}`);
+  for (const [source, bucket] of [...bySource].sort(([a], [b]) => a.localeCompare(b))) {
+    console.log(`  ${source}: ${bucket.count} entries, ${formatBytes(bucket.size)}`);
+  }
+  console.log(`oldest: ${oldest.key} (${oldest.storedAt})`);
+  console.log(`newest: ${newest.key} (${newest.storedAt})`);
+
+  const counters = await loadCacheCounters();
+  if (counters !== null) {
+    const total = counters.hits + counters.misses;
+    const rate = total > 0 ? ` (${((counters.hits / total) * 100).toFixed(0)}% hit rate)` : "";
+    console.log(`lookups: ${counters.hits} hits, ${counters.misses} misses${rate}`);
+  }
+}
+
+/** Parse a size limit like `100MB`, `512KB`, or a bare byte count. */
+function parseSize(text: string): number {
+  const match = text.match(/^(\d+(?:\.\d+)?)\s*(B|KB|MB|GB)?$/i);
+  if (!match?.[1]) {
+    throw new Error(`Invalid size: ${text} (expected e.g. 100MB)`);
+  }
+  const unit = (match[2] ?? "B").toUpperCase();
+  const factor = { B: 1, KB: 1024, MB: 1024 * 1024, GB: 1024 * 1024 * 1024 }[unit] ?? 1;
+  return Number(match[1]) * factor;
+}
+
+export async function runCachePrune(args: readonly string[]): Promise<void> {
+  let olderThanMs: number | undefined;
+  let maxSize: number | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    const value = args[i + 1];
+    if (args[i] === "--older-than") {
+      if (value === undefined) throw new Error("Missing value for --older-than");
+      olderThanMs = parseDuration(value);
+      i += 1;
+    } else if (args[i] === "--max-size") {
+      if (value === undefined) throw new Error("Missing value for --max-size");
+      maxSize = parseSize(value);
+      i += 1;
+    } else {
+      throw new Error(`Unknown cache prune argument: ${args[i]}`);
+    }
+  }
+
+  const config = await loadConfig(".");
+  const cache: Cache = openCache(config.global.cacheBackend);
+  const now = Date.now();
+
+  let kept: CacheEntry[] = [];
+  let removed = 0;
+  for (const entry of await cache.entries()) {
+    const tooOld = olderThanMs !== undefined &&
+      Date.parse(entry.storedAt) < now - olderThanMs;
+    if (!isFresh(entry, now) || tooOld) {
+      await cache.delete(entry.key);
+      removed += 1;
+    } else {
+      kept.push(entry);
+    }
+  }
+
+  if (maxSize !== undefined) {
+    // No per-entry access times are tracked, so least-recently-stored is the
+    // eviction order when trimming down to the size limit.
+    kept = kept.sort((a, b) => a.storedAt.localeCompare(b.storedAt));
+    let total = kept.reduce((sum, entry) => sum + entrySize(entry), 0);
+    while (total > maxSize && kept.length > 0) {
+      const oldest = kept.shift() as CacheEntry;
+      await cache.delete(oldest.key);
+      total -= entrySize(oldest);
+      removed += 1;
+    }
+  }
+
+  console.log(
+    `Pruned ${removed} entr${removed === 1 ? "y" : "ies"}, ${kept.length} remaining`,
+  );
+}
+
+/**
+ * Write every cache entry into one portable JSON document, so CI pipelines
+ * can persist the cache as a single artifact instead of tarring the raw
+ * directory (whose layout differs between backends).
+ */
+export async function runCacheExport(args: readonly string[]): Promise<void> {
+  const path = args[0];
+  if (path === undefined || args.length > 1) {
+    throw new Error("Usage: treeupdt cache export <file>");
+  }
+
+  const config = await loadConfig(".");
+  const cache = openCache(config.global.cacheBackend);
+  const entries = await cache.entries();
+  await Deno.writeTextFile(
+    path,
+    `${JSON.stringify({ "treeupdt-cache": exportVersion, entries }, null, 2)}\n`,
+  );
+  console.log(`Exported ${entries.length} entr${entries.length === 1 ? "y" : "ies"} to ${path}`);
+}
+
+/** Restore entries from `cache export`, skipping malformed or expired ones. */
+export async function runCacheImport(args: readonly string[]): Promise<void> {
+  const path = args[0];
+  if (path === undefined || args.length > 1) {
+    throw new Error("Usage: treeupdt cache import <file>");
+  }
+
+  const parsed: unknown = JSON.parse(await Deno.readTextFile(path));
+  if (!isRecord(parsed) || parsed["treeupdt-cache"] !== exportVersion) {
+    throw new Error(`${path}: not a treeupdt cache export (version ${exportVersion})`);
+  }
+  const rawEntries = parsed["entries"];
+  if (!Array.isArray(rawEntries)) {
+    throw new Error(`${path}: expected an entries array`);
+  }
+
+  const config = await loadConfig(".");
+  const cache = openCache(config.global.cacheBackend);
+  const now = Date.now();
+  let imported = 0;
+  let skipped = 0;
+  for (const raw of rawEntries) {
+    const entry = parseCacheEntry(raw);
+    if (entry === null || !isFresh(entry, now)) {
+      skipped += 1;
+      continue;
+    }
+    await cache.put(entry);
+    imported += 1;
+  }
+  console.log(
+    `Imported ${imported} entr${imported === 1 ? "y" : "ies"} from ${path}` +
+      (skipped > 0 ? ` (${skipped} skipped: malformed or expired)` : ""),
+  );
+}
+
+export async function runCache(args: readonly string[]): Promise<void> {
+  switch (args[0]) {
+    case "stats":
+      await runCacheStats();
+      break;
+    case "prune":
+      await runCachePrune(args.slice(1));
+      break;
+    case "export":
+      await runCacheExport(args.slice(1));
+      break;
+    case "import":
+      await runCacheImport(args.slice(1));
+      break;
+    default:
+      throw new Error(`Unknown cache subcommand: ${args[0] ?? "<missing>"}`);
+  }
+}
@@ -0,0 +1,115 @@
+import { candidateTags, fetchChangelogExcerpt, fetchGithubReleaseNotes } from "../changelog.ts";
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { matchesPackageName, parsePathSpec } from "../select.ts";
+import { isNewerVersion } from "../semverRange.ts";
+import { defaultSourceRegistry } from "../sources.ts";
+import type { Package } from "../types.ts";
+
+/** Cap on fetched release bodies so a very stale package stays readable. */
+const maxVersions = 30;
+
+function usage(): never {
+  throw new Error(
+    "Usage: treeupdt changelog <package> [--from <version>] [--to <version>|latest]",
+  );
+}
+
+/**
+ * `treeupdt changelog <package> [--from v] [--to v|latest]`: fetch and render
+ * the release notes for every version between `from` (default: the version in
+ * the manifest) and `to` (default: the newest stable release), using the same
+ * GitHub release/CHANGELOG machinery as the PR body builder.
+ */
+export async function runChangelog(args: readonly string[]): Promise<void> {
+  let name: string | undefined;
+  let from: string | undefined;
+  let to = "latest";
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--from") {
+      from = args[i + 1];
+      if (from === undefined) usage();
+      i += 1;
+    } else if (arg === "--to") {
+      const value = args[i + 1];
+      if (value === undefined) usage();
+      to = value;
+      i += 1;
+    } else if (arg !== undefined && name === undefined) {
+      name = arg;
+    } else {
+      usage();
+    }
+  }
+  if (name === undefined) usage();
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const spec = parsePathSpec(name);
+  const packageName = spec.packageName ?? spec.pattern;
+  const pkg: Package | undefined =
+    (await scanTree(".", registry, config.global.excludePaths ?? []))
+      .find((candidate) =>
+        matchesPackageName(packageName, candidate) &&
+        (spec.packageName === null || candidate.file === spec.pattern)
+      );
+  if (pkg === undefined) {
+    throw new Error(`No scanned package matches ${name}`);
+  }
+
+  const githubHint = pkg.sourceHints.find((hint) => hint.source === "github");
+  if (githubHint === undefined) {
+    throw new Error(
+      `${pkg.name}: release notes need a github source hint; its sources are ` +
+        (pkg.sourceHints.map((hint) => hint.source).join(", ") || "none"),
+    );
+  }
+  const [owner, repo] = githubHint.identifier.split("/");
+  if (owner === undefined || repo === undefined) {
+    throw new Error(`${pkg.name}: malformed github identifier ${githubHint.identifier}`);
+  }
+
+  const sources = defaultSourceRegistry(config);
+  const source = sources.get("github");
+  if (!source) throw new Error("No github source registered");
+  const versions = await source.listVersions(githubHint.identifier);
+
+  const fromVersion = from ?? pkg.version;
+  const stable = versions.filter((v) => v.prerelease !== true && v.yanked !== true);
+  const toVersion = to === "latest" ? stable[0]?.version : to;
+  if (toVersion === undefined) {
+    throw new Error(`${pkg.name}: no stable releases found`);
+  }
+
+  // Oldest first, so the notes read like a history.
+  const between = stable
+    .filter((v) =>
+      isNewerVersion(fromVersion, v.version) &&
+      (v.version === toVersion || !isNewerVersion(toVersion, v.version))
+    )
+    .reverse();
+  if (between.length === 0) {
+    console.log(`${pkg.name}: no versions between ${fromVersion} and ${toVersion}`);
+    return;
+  }
+
+  for (const [i, version] of between.entries()) {
+    if (i === maxVersions) {
+      console.log(`... and ${between.length - i} more versions (narrow with --from/--to)`);
+      break;
+    }
+    const notes = await fetchGithubReleaseNotes(owner, repo, candidateTags(version.version));
+    const tag = notes?.tag ?? candidateTags(version.version)[0] ?? version.version;
+    const body = notes?.body ??
+      await fetchChangelogExcerpt(owner, repo, tag, version.version);
+    console.log(`## ${version.version}`);
+    console.log();
+    console.log(body !== null && body !== undefined ? body.trim() : "(no release notes found)");
+    console.log();
+  }
+}
@@ -0,0 +1,388 @@
+import { interruptSignal } from "../cancel.ts";
+import { candidateTags, fetchGithubReleaseNotes } from "../changelog.ts";
+import { runCheckPipeline } from "../check.ts";
+import { loadConfig } from "../config.ts";
+import { type Filter } from "../filter.ts";
+import { findLockfile, type Lockfile, parseLockfile, transitiveDependencies } from "../lockfile.ts";
+import { newlyActionable, sendNotifications } from "../notify.ts";
+import { renderCsv } from "../output/csv.ts";
+import { renderHtml } from "../output/html.ts";
+import { renderJunit } from "../output/junit.ts";
+import { renderMarkdown } from "../output/markdown.ts";
+import { isStderrTerminal } from "../progress.ts";
+import { parsePathSpec, type PathSpec } from "../select.ts";
+import { changedOnly, loadPreviousEntries, saveEntries } from "../state.ts";
+import type { UpdateEntry } from "../types.ts";
+import { renderTag } from "../versionTemplate.ts";
+
+/** Exit code for `--exit-code` when updates are available and nothing failed. */
+export const exitCodeUpdatesAvailable = 10;
+/** Exit code for `--exit-code` when one or more checks errored. */
+export const exitCodeErrors = 1;
+
+const semverLevels = ["major", "minor", "patch"] as const;
+
+type ParsedArgs = Readonly<{
+  jobs: number | undefined;
+  output: string;
+  exitCode: boolean;
+  changelog: boolean;
+  only: readonly string[];
+  selectors: readonly PathSpec[];
+  changedOnly: boolean;
+  impact: boolean;
+  respectSchedule: boolean;
+  offline: boolean;
+  notify: boolean;
+  filter: Filter;
+  profile: string | undefined;
+}>;
+
+function parseArgs(args: readonly string[]): ParsedArgs {
+  let jobs: number | undefined;
+  let output = "text";
+  let exitCode = false;
+  let changelog = false;
+  const only: string[] = [];
+  const selectors: PathSpec[] = [];
+  let changedOnly = false;
+  let impact = false;
+  let respectSchedule = false;
+  let offline = false;
+  let notify = false;
+  let profile: string | undefined;
+  const fileTypes: string[] = [];
+  const filterSources: string[] = [];
+  const namePatterns: string[] = [];
+
+  const takeValue = (i: number, flag: string): string => {
+    const value = args[i + 1];
+    if (value === undefined) {
+      throw new Error(`Missing value for ${flag}`);
+    }
+    return value;
+  };
+
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--exit-code") {
+      exitCode = true;
+    } else if (arg === "--changelog") {
+      changelog = true;
+    } else if (arg === "--changed-only") {
+      changedOnly = true;
+    } else if (arg === "--impact") {
+      impact = true;
+    } else if (arg === "--respect-schedule") {
+      respectSchedule = true;
+    } else if (arg === "--offline") {
+      offline = true;
+    } else if (arg === "--notify") {
+      notify = true;
+    } else if (arg === "--type") {
+      fileTypes.push(takeValue(i, arg));
+      i += 1;
+    } else if (arg === "--source") {
+      filterSources.push(takeValue(i, arg));
+      i += 1;
+    } else if (arg === "--name") {
+      namePatterns.push(takeValue(i, arg));
+      i += 1;
+    } else if (arg === "--profile") {
+      profile = takeValue(i, arg);
+      i += 1;
+    } else if (arg === "--only") {
+      const value = args[i + 1] ?? "";
+      if (!(semverLevels as readonly string[]).includes(value)) {
+        throw new Error(
+          `Invalid --only value: ${value || "<missing>"} (expected major|minor|patch)`,
+        );
+      }
+      only.push(value);
+      i += 1;
+    } else if (arg === "--jobs" || arg === "-j") {
+      const value = Number(args[i + 1]);
+      if (!Number.isInteger(value) || value < 1) {
+        throw new Error(`Invalid --jobs value: ${args[i + 1] ?? "<missing>"}`);
+      }
+      jobs = value;
+      i += 1;
+    } else if (arg === "--output" || arg === "-o") {
+      output = args[i + 1] ?? "";
+      i += 1;
+    } else if (arg !== undefined && !arg.startsWith("-")) {
+      selectors.push(parsePathSpec(arg));
+    } else {
+      throw new Error(`Unknown check argument: ${arg}`);
+    }
+  }
+  return {
+    jobs,
+    output,
+    exitCode,
+    changelog,
+    only,
+    selectors,
+    changedOnly,
+    impact,
+    respectSchedule,
+    offline,
+    notify,
+    filter: { fileTypes, sources: filterSources, namePatterns },
+    profile,
+  };
+}
+
+/** Drop updates whose magnitude is outside the repeatable `--only` filter. */
+function filterByLevel(
+  entries: readonly UpdateEntry[],
+  only: readonly string[],
+): UpdateEntry[] {
+  if (only.length === 0) return [...entries];
+  return entries.filter((entry) =>
+    entry.updateAvailable !== true ||
+    (entry.semverLevel !== undefined && only.includes(entry.semverLevel))
+  );
+}
+
+/** Print GitHub release notes for updatable packages (`check --changelog`). */
+async function renderChangelogs(entries: readonly UpdateEntry[]): Promise<void> {
+  const config = await loadConfig(".");
+  for (const entry of entries) {
+    if (entry.updateAvailable !== true || entry.source !== "github") continue;
+    if (entry.identifier === undefined || entry.latest === undefined) continue;
+    const [owner, repo] = entry.identifier.split("/");
+    if (!owner || !repo) continue;
+
+    const tagTemplate = config.packages[entry.name]?.tagTemplate;
+    const tags = tagTemplate !== undefined
+      ? [renderTag(tagTemplate, entry.latest)]
+      : candidateTags(entry.latest);
+    const notes = await fetchGithubReleaseNotes(owner, repo, tags);
+    if (!notes) continue;
+    console.log();
+    console.log(`--- ${entry.name} ${entry.current} -> ${entry.latest} ---`);
+    console.log(notes.body.trim());
+  }
+}
+
+/** Cross-reference lockfiles to show each update's blast radius (`--impact`). */
+async function renderImpact(entries: readonly UpdateEntry[]): Promise<void> {
+  const lockfiles = new Map<string, Lockfile | null>();
+  for (const entry of entries) {
+    if (entry.updateAvailable !== true || entry.identifier === undefined) continue;
+    const path = await findLockfile(entry.file, entry.fileType);
+    if (path === null) continue;
+
+    let lock = lockfiles.get(path);
+    if (lock === undefined) {
+      lock = parseLockfile(path, await Deno.readTextFile(path));
+      lockfiles.set(path, lock);
+    }
+    if (lock === null) continue;
+
+    const impacted = transitiveDependencies(lock, entry.identifier);
+    if (impacted.length === 0) continue;
+    console.log(
+      `${entry.name} (${entry.file}): updating may also change ${impacted.length} ` +
+        `transitive package${impacted.length === 1 ? "" : "s"}: ${impacted.join(", ")}`,
+    );
+  }
+}
+
+/** Rough human age of an ISO timestamp, e.g. `14 months ago`. */
+function formatAge(iso: string): string {
+  const elapsed = Date.now() - Date.parse(iso);
+  if (!Number.isFinite(elapsed) || elapsed < 0) return iso;
+  const days = Math.floor(elapsed / 86_400_000);
+  if (days < 31) return `${days} day${days === 1 ? "" : "s"} ago`;
+  const months = Math.floor(days / 30);
+  if (months < 12) return `${months} month${months === 1 ? "" : "s"} ago`;
+  const years = Math.floor(months / 12);
+  return `${years} year${years === 1 ? "" : "s"} ago`;
+}
+
+/** `file:line:col` when the scanner recorded a span, the plain file otherwise. */
+function location(entry: UpdateEntry): string {
+  return entry.span !== undefined
+    ? `${entry.file}:${entry.span.line}:${entry.span.column}`
+    : entry.file;
+}
+
+function renderText(entries: readonly UpdateEntry[]): void {
+  let updates = 0;
+  let errors = 0;
+  const levels: Record<string, number> = {};
+  const groupUpdates = new Map<string, number>();
+
+  for (const entry of entries) {
+    if (entry.ignored === true) {
+      const reason = entry.ignoreReason !== undefined ? `: ${entry.ignoreReason}` : "";
+      console.log(`${entry.name} (${entry.file}): ignored by annotation${reason}`);
+      continue;
+    }
+    if (entry.error !== undefined) {
+      errors += 1;
+      if (entry.unknownPackage === true) {
+        console.log(`${entry.name} (${location(entry)}): unknown package: not found upstream`);
+      } else {
+        console.log(`${entry.name} (${location(entry)}): error: ${entry.error}`);
+      }
+      continue;
+    }
+    if (entry.pinVersion !== undefined) {
+      if (entry.pinDrift === true) {
+        console.log(
+          `${entry.name} (${entry.file}): ${entry.current} drifts from pin ` +
+            `${entry.pinVersion} (run \`update --enforce-pins\` to restore)`,
+        );
+      }
+      continue;
+    }
+    if (entry.eol === true) {
+      const date = entry.eolDate !== undefined ? ` (since ${entry.eolDate})` : "";
+      console.log(
+        `WARNING: ${entry.name} (${entry.file}): pinned version ${entry.current} ` +
+          `is end-of-life${date}`,
+      );
+    }
+    if (entry.currentVersionStatus === "yanked") {
+      console.log(
+        `WARNING: ${entry.name} (${entry.file}): current version ${entry.current} is yanked`,
+      );
+    } else if (entry.currentVersionStatus === "deprecated") {
+      const message = entry.deprecationMessage !== undefined
+        ? `: ${entry.deprecationMessage}`
+        : "";
+      console.log(
+        `WARNING: ${entry.name} (${entry.file}): current version ${entry.current} ` +
+          `is deprecated${message}`,
+      );
+    }
+    if (
+      entry.currentLicense !== undefined && entry.latestLicense !== undefined &&
+      entry.currentLicense !== entry.latestLicense
+    ) {
+      console.log(
+        `WARNING: ${entry.name} (${entry.file}): license changes from ` +
+          `${entry.currentLicense} to ${entry.latestLicense} on update`,
+      );
+    }
+    if (entry.updateAvailable === true) {
+      updates += 1;
+      if (entry.group !== undefined) {
+        groupUpdates.set(entry.group, (groupUpdates.get(entry.group) ?? 0) + 1);
+      }
+      if (entry.semverLevel !== undefined) {
+        levels[entry.semverLevel] = (levels[entry.semverLevel] ?? 0) + 1;
+      }
+      const behind = entry.releasesBehind !== undefined && entry.releasesBehind > 1
+        ? `, ${entry.releasesBehind} releases behind`
+        : "";
+      const age = entry.currentPublishedAt !== undefined
+        ? `, current released ${formatAge(entry.currentPublishedAt)}`
+        : "";
+      console.log(
+        `${entry.name} (${entry.file}): ${entry.current} -> ${entry.latest}` +
+          `${entry.semverLevel !== undefined ? ` (${entry.semverLevel})` : ""} [${entry.source}]` +
+          behind + age,
+      );
+    } else if (entry.blockedByStrategy === true) {
+      console.log(
+        `${entry.name} (${entry.file}): ${entry.current} -> ${entry.latest} ` +
+          `blocked by strategy ${entry.strategy}`,
+      );
+    }
+  }
+
+  console.log();
+  const breakdown = semverLevels
+    .map((level) => `${levels[level] ?? 0} ${level}`)
+    .join(", ");
+  console.log(
+    `${entries.length} checked, ${updates} updates available (${breakdown}), ${errors} errors`,
+  );
+  for (const [group, count] of groupUpdates) {
+    console.log(`  group ${group}: ${count} update${count === 1 ? "" : "s"}`);
+  }
+}
+
+export async function runCheck(args: readonly string[]): Promise<void> {
+  const parsed = parseArgs(args);
+
+  const report = await runCheckPipeline(".", {
+    ...(parsed.jobs !== undefined ? { jobs: parsed.jobs } : {}),
+    selectors: parsed.selectors,
+    filter: parsed.filter,
+    respectSchedule: parsed.respectSchedule,
+    ...(parsed.offline ? { offline: true } : {}),
+    ...(parsed.profile !== undefined ? { profile: parsed.profile } : {}),
+    progress: parsed.output === "text" && isStderrTerminal(),
+    signal: interruptSignal(),
+  });
+
+  const previous = parsed.changedOnly || parsed.notify ? await loadPreviousEntries(".") : null;
+  // Partial runs would shrink the baseline, so only full checks update it.
+  if (parsed.selectors.length === 0 && !parsed.respectSchedule) {
+    await saveEntries(".", report.entries);
+  }
+
+  let entries = filterByLevel(report.entries, parsed.only);
+  if (parsed.changedOnly) {
+    entries = changedOnly(entries, previous);
+  }
+
+  switch (parsed.output) {
+    case "json":
+      console.log(JSON.stringify(entries, null, 2));
+      break;
+    case "markdown":
+      console.log(renderMarkdown(entries));
+      break;
+    case "html":
+      console.log(renderHtml(entries));
+      break;
+    case "csv":
+      console.log(renderCsv(entries));
+      break;
+    case "junit":
+      console.log(renderJunit(entries));
+      break;
+    case "text":
+      renderText(entries);
+      if (parsed.impact) {
+        console.log();
+        await renderImpact(entries);
+      }
+      if (parsed.changelog) {
+        await renderChangelogs(entries);
+      }
+      break;
+    default:
+      throw new Error(`Unknown output format: ${parsed.output}`);
+  }
+
+  if (parsed.notify) {
+    const webhooks = (await loadConfig(".")).global.webhooks ?? [];
+    if (webhooks.length === 0) {
+      throw new Error("check --notify needs global.webhooks in .treeupdt.json");
+    }
+    const fresh = newlyActionable(report.entries, previous);
+    if (fresh.length === 0) {
+      console.log("Nothing new since the last run; no notifications sent");
+    } else {
+      await sendNotifications(webhooks, fresh);
+      console.log(
+        `Notified ${webhooks.length} webhook${webhooks.length === 1 ? "" : "s"} ` +
+          `about ${fresh.length} entr${fresh.length === 1 ? "y" : "ies"}`,
+      );
+    }
+  }
+
+  if (parsed.exitCode) {
+    const hasErrors = entries.some((entry) => entry.error !== undefined);
+    const hasUpdates = entries.some((entry) => entry.updateAvailable === true);
+    if (hasErrors) Deno.exit(exitCodeErrors);
+    if (hasUpdates) Deno.exit(exitCodeUpdatesAvailable);
+  }
+}
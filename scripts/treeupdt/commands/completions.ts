@@ -0,0 +1,149 @@
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+
+const shells = ["bash", "zsh", "fish", "powershell"] as const;
+
+type Shell = (typeof shells)[number];
+
+/** User-facing commands, in the order the help text lists them. */
+const commands = [
+  "scan",
+  "check",
+  "outdated",
+  "tree",
+  "diff",
+  "snapshot",
+  "verify",
+  "report",
+  "stats",
+  "sbom",
+  "badge",
+  "explain",
+  "update",
+  "plan",
+  "apply",
+  "history",
+  "config",
+  "cache",
+  "serve",
+  "pin",
+  "unpin",
+  "ignore",
+  "annotate",
+  "init",
+  "changelog",
+  "search",
+  "self-update",
+  "help",
+] as const;
+
+/** Commands whose arguments are package path-specs, completed dynamically. */
+const specCommands = ["scan", "check", "outdated", "explain", "update"] as const;
+
+function bashScript(): string {
+  return `_treeupdt() {
+  local cur=\${COMP_WORDS[COMP_CWORD]}
+  if [ "$COMP_CWORD" -eq 1 ]; then
+    COMPREPLY=($(compgen -W "${commands.join(" ")}" -- "$cur"))
+    return
+  fi
+  case \${COMP_WORDS[1]} in
+    ${specCommands.join("|")})
+      COMPREPLY=($(compgen -W "$(treeupdt __complete specs 2>/dev/null)" -- "$cur"))
+      ;;
+  esac
+}
+complete -F _treeupdt treeupdt
+`;
+}
+
+function zshScript(): string {
+  return `#compdef treeupdt
+_treeupdt() {
+  if (( CURRENT == 2 )); then
+    compadd ${commands.join(" ")}
+  else
+    case $words[2] in
+      ${specCommands.join("|")})
+        compadd -- \${(f)"$(treeupdt __complete specs 2>/dev/null)"}
+        ;;
+    esac
+  fi
+}
+_treeupdt "$@"
+`;
+}
+
+function fishScript(): string {
+  return `complete -c treeupdt -f
+complete -c treeupdt -n "__fish_use_subcommand" -a "${commands.join(" ")}"
+complete -c treeupdt -n "__fish_seen_subcommand_from ${specCommands.join(" ")}" \\
+  -a "(treeupdt __complete specs 2>/dev/null)"
+`;
+}
+
+function powershellScript(): string {
+  return `Register-ArgumentCompleter -Native -CommandName treeupdt -ScriptBlock {
+  param($wordToComplete, $commandAst, $cursorPosition)
+  $words = $commandAst.CommandElements | ForEach-Object { $_.ToString() }
+  $candidates = if ($words.Count -le 1 -or ($words.Count -eq 2 -and $wordToComplete)) {
+    "${commands.join('", "')}"
+  } elseif ("${specCommands.join('", "')}" -contains $words[1]) {
+    treeupdt __complete specs 2>$null
+  } else {
+    @()
+  }
+  $candidates | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+    [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+  }
+}
+`;
+}
+
+/**
+ * `treeupdt completions bash|zsh|fish|powershell`: emit a completion script
+ * for the shell to eval or install. Path-spec arguments complete dynamically
+ * through the hidden `__complete specs` entry point, which runs a quick scan.
+ */
+export function runCompletions(args: readonly string[]): void {
+  const shell = args[0];
+  if (shell === undefined || !(shells as readonly string[]).includes(shell) || args.length > 1) {
+    throw new Error(`Usage: treeupdt completions ${shells.join("|")}`);
+  }
+  switch (shell as Shell) {
+    case "bash":
+      console.log(bashScript());
+      break;
+    case "zsh":
+      console.log(zshScript());
+      break;
+    case "fish":
+      console.log(fishScript());
+      break;
+    case "powershell":
+      console.log(powershellScript());
+      break;
+  }
+}
+
+/** Hidden helper behind the completion scripts; not listed in the help. */
+export async function runComplete(args: readonly string[]): Promise<void> {
+  if (args[0] !== "specs") {
+    throw new Error(`Unknown completion query: ${args[0] ?? "<missing>"}`);
+  }
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = await scanTree(".", registry, config.global.excludePaths ?? []);
+  const specs = new Set<string>();
+  for (const pkg of packages) {
+    specs.add(pkg.file);
+    specs.add(`${pkg.file}:${pkg.name}`);
+  }
+  for (const spec of [...specs].sort()) {
+    console.log(spec);
+  }
+}
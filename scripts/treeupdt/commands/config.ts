@@ -0,0 +1,303 @@
+import { basename, join } from "node:path";
+
+import { isRecord } from "../../updater/assert.ts";
+import { fileExists } from "../../updater/fs.ts";
+import { type JsonValue, readJsonObjectFile, writeJsonFile } from "../../updater/jsonFile.ts";
+import {
+  configFileName,
+  lintConfig,
+  migrateConfigData,
+  parseConfig,
+  userConfigPath,
+} from "../config.ts";
+import { configJsonSchema } from "../configSchema.ts";
+import { globToRegExp } from "../glob.ts";
+import { importDependabot } from "../importers/dependabot.ts";
+import { type ImportResult, importRenovate } from "../importers/renovate.ts";
+import { parseDuration } from "../releaseAge.ts";
+import { validateSchedule } from "../schedule.ts";
+import { validateExtractVersion } from "../versionTemplate.ts";
+
+/** Validate one config file, printing findings; returns the issue count. */
+async function validateFile(path: string): Promise<number> {
+  let data: unknown;
+  try {
+    data = JSON.parse(await Deno.readTextFile(path));
+  } catch (err) {
+    console.log(`${path}: not valid JSON: ${err instanceof Error ? err.message : String(err)}`);
+    return 1;
+  }
+
+  const issues = lintConfig(data, path);
+  try {
+    const config = parseConfig(data, path);
+    for (const pattern of config.global.excludePaths ?? []) {
+      try {
+        globToRegExp(pattern);
+      } catch {
+        issues.push(`${path}.global.exclude-paths: invalid pattern ${pattern}`);
+      }
+    }
+    for (const pattern of config.global.filters?.namePatterns ?? []) {
+      try {
+        globToRegExp(pattern);
+      } catch {
+        issues.push(`${path}.global.filters.name-patterns: invalid pattern ${pattern}`);
+      }
+    }
+    const durations: [string, string | undefined][] = [
+      [`${path}.global.minimum-release-age`, config.global.minimumReleaseAge],
+      [`${path}.global.cache-ttl`, config.global.cacheTtl],
+      ...Object.entries(config.packages).map(([name, pkg]): [string, string | undefined] => [
+        `${path}.packages.${name}.minimum-release-age`,
+        pkg.minimumReleaseAge,
+      ]),
+      ...Object.entries(config.sources).map(([type, source]): [string, string | undefined] => [
+        `${path}.sources.${type}.cache-ttl`,
+        source.cacheTtl,
+      ]),
+    ];
+    for (const [context, duration] of durations) {
+      if (duration === undefined) continue;
+      try {
+        parseDuration(duration);
+      } catch {
+        issues.push(`${context}: invalid duration ${duration}`);
+      }
+    }
+    const schedules: [string, string | undefined][] = [
+      ...Object.entries(config.packages).map(([name, pkg]): [string, string | undefined] => [
+        `${path}.packages.${name}.schedule`,
+        pkg.schedule,
+      ]),
+      ...Object.entries(config.groups).map(([name, group]): [string, string | undefined] => [
+        `${path}.groups.${name}.schedule`,
+        group.schedule,
+      ]),
+    ];
+    for (const [context, schedule] of schedules) {
+      if (schedule === undefined) continue;
+      try {
+        validateSchedule(schedule);
+      } catch (err) {
+        issues.push(`${context}: ${err instanceof Error ? err.message : String(err)}`);
+      }
+    }
+    for (const [name, pkg] of Object.entries(config.packages)) {
+      if (pkg.extractVersion === undefined) continue;
+      try {
+        validateExtractVersion(pkg.extractVersion);
+      } catch (err) {
+        issues.push(
+          `${path}.packages.${name}.extract-version: ` +
+            (err instanceof Error ? err.message : String(err)),
+        );
+      }
+    }
+  } catch (err) {
+    issues.push(err instanceof Error ? err.message : String(err));
+  }
+
+  for (const issue of issues) {
+    console.log(issue);
+  }
+  return issues.length;
+}
+
+export async function runConfigValidate(): Promise<void> {
+  const candidates = [userConfigPath(), join(".", configFileName)];
+  let found = 0;
+  let issues = 0;
+  for (const path of candidates) {
+    if (!(await fileExists(path))) continue;
+    found += 1;
+    issues += await validateFile(path);
+  }
+
+  if (found === 0) {
+    console.log("No config files found; defaults apply");
+    return;
+  }
+  if (issues === 0) {
+    console.log(`${found} config file${found === 1 ? "" : "s"} OK`);
+    return;
+  }
+  Deno.exit(1);
+}
+
+/** Merge an imported fragment into existing config: arrays union, tables recurse. */
+function mergeRaw(
+  base: Record<string, JsonValue>,
+  overlay: Record<string, JsonValue>,
+): Record<string, JsonValue> {
+  const merged = { ...base };
+  for (const [key, value] of Object.entries(overlay)) {
+    const existing = merged[key];
+    if (Array.isArray(existing) && Array.isArray(value)) {
+      merged[key] = [...new Set([...existing, ...value])];
+    } else if (isRecord(existing) && isRecord(value)) {
+      merged[key] = mergeRaw(existing, value);
+    } else if (existing === undefined) {
+      merged[key] = value;
+    }
+    // Existing scalar settings win; imports never clobber local choices.
+  }
+  return merged;
+}
+
+export async function runConfigImport(args: readonly string[]): Promise<void> {
+  let from: string | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--from") {
+      from = args[i + 1];
+      i += 1;
+    } else {
+      throw new Error(`Unknown config import argument: ${args[i]}`);
+    }
+  }
+  if (from === undefined) {
+    throw new Error("Usage: treeupdt config import --from <renovate.json>");
+  }
+
+  let result: ImportResult;
+  const name = basename(from);
+  if (name.includes("renovate")) {
+    result = importRenovate(JSON.parse(await Deno.readTextFile(from)));
+  } else if (name.includes("dependabot")) {
+    result = importDependabot(await Deno.readTextFile(from));
+  } else {
+    throw new Error(`Cannot import ${from}: unrecognized config format`);
+  }
+
+  for (const warning of result.warnings) {
+    console.log(`warning: ${warning}`);
+  }
+  if (Object.keys(result.config).length === 0) {
+    console.log(`Nothing to import from ${from}`);
+    return;
+  }
+
+  const existing = (await fileExists(configFileName))
+    ? await readJsonObjectFile(configFileName)
+    : {};
+  await writeJsonFile(configFileName, mergeRaw(existing, result.config));
+  console.log(`Imported ${from} into ${configFileName}`);
+}
+
+/** Split a dotted key path, keeping `.` inside quoted segments literal. */
+function parseKeyPath(path: string): string[] {
+  const segments = path.match(/"[^"]*"|[^.]+/g) ?? [];
+  return segments.map((segment) => segment.replace(/^"/, "").replace(/"$/, ""));
+}
+
+export async function runConfigGet(args: readonly string[]): Promise<void> {
+  const [path] = args;
+  if (!path || args.length !== 1) {
+    throw new Error("Usage: treeupdt config get <key.path>");
+  }
+
+  let value: JsonValue | undefined = (await fileExists(configFileName))
+    ? await readJsonObjectFile(configFileName)
+    : {};
+  for (const segment of parseKeyPath(path)) {
+    if (!isRecord(value)) {
+      value = undefined;
+      break;
+    }
+    value = value[segment];
+  }
+  if (value === undefined) {
+    console.log(`${path} is not set`);
+    Deno.exit(1);
+  }
+  console.log(JSON.stringify(value, null, 2));
+}
+
+export async function runConfigSet(args: readonly string[]): Promise<void> {
+  const [path, raw] = args;
+  if (!path || raw === undefined || args.length !== 2) {
+    throw new Error("Usage: treeupdt config set <key.path> <value>");
+  }
+  const segments = parseKeyPath(path);
+  const last = segments.pop();
+  if (last === undefined) {
+    throw new Error(`Invalid key path: ${path}`);
+  }
+
+  // Values that parse as JSON are stored typed; anything else is a string,
+  // so `config set sources.github.concurrency 2` does the expected thing.
+  let value: JsonValue;
+  try {
+    value = JSON.parse(raw);
+  } catch {
+    value = raw;
+  }
+
+  const data = (await fileExists(configFileName)) ? await readJsonObjectFile(configFileName) : {};
+  let table: Record<string, JsonValue> = data;
+  for (const segment of segments) {
+    const existing = table[segment];
+    if (existing === undefined) {
+      const created: Record<string, JsonValue> = {};
+      table[segment] = created;
+      table = created;
+    } else if (isRecord(existing)) {
+      table = existing;
+    } else {
+      throw new Error(`${segments.join(".")}: not a table; refusing to overwrite`);
+    }
+  }
+  table[last] = value;
+
+  for (const issue of lintConfig(data, configFileName)) {
+    console.log(`warning: ${issue}`);
+  }
+  await writeJsonFile(configFileName, data);
+  console.log(`Set ${path} in ${configFileName}`);
+}
+
+export async function runConfigMigrate(): Promise<void> {
+  let migrated = 0;
+  for (const path of [userConfigPath(), join(".", configFileName)]) {
+    if (!(await fileExists(path))) continue;
+    const data = await readJsonObjectFile(path);
+    const changes = migrateConfigData(data);
+    if (changes.length === 0) {
+      console.log(`${path}: already current`);
+      continue;
+    }
+    await writeJsonFile(path, data);
+    for (const change of changes) {
+      console.log(`${path}: ${change}`);
+    }
+    migrated += 1;
+  }
+  if (migrated === 0) {
+    console.log("Nothing to migrate");
+  }
+}
+
+export async function runConfig(args: readonly string[]): Promise<void> {
+  switch (args[0]) {
+    case "validate":
+      await runConfigValidate();
+      break;
+    case "schema":
+      console.log(JSON.stringify(configJsonSchema(), null, 2));
+      break;
+    case "import":
+      await runConfigImport(args.slice(1));
+      break;
+    case "get":
+      await runConfigGet(args.slice(1));
+      break;
+    case "set":
+      await runConfigSet(args.slice(1));
+      break;
+    case "migrate":
+      await runConfigMigrate();
+      break;
+    default:
+      throw new Error(`Unknown config subcommand: ${args[0] ?? "<missing>"}`);
+  }
+}
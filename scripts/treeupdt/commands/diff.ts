@@ -0,0 +1,112 @@
+import { isRecord } from "../../updater/assert.ts";
+import { runCaptureChecked, trimLines } from "../../updater/command.ts";
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, type ScannerRegistry, scanTree } from "../scan.ts";
+
+/** The part of a scanned package a diff cares about. */
+type SnapshotEntry = Readonly<{
+  name: string;
+  version: string;
+  file: string;
+}>;
+
+function parseSnapshot(data: unknown, context: string): SnapshotEntry[] {
+  if (!Array.isArray(data)) {
+    throw new Error(`${context}: expected a package array (from \`treeupdt scan\`)`);
+  }
+  return data.map((item, i) => {
+    if (
+      !isRecord(item) || typeof item["name"] !== "string" ||
+      typeof item["version"] !== "string" || typeof item["file"] !== "string"
+    ) {
+      throw new Error(`${context}[${i}]: expected name, version, and file strings`);
+    }
+    return { name: item["name"], version: item["version"], file: item["file"] };
+  });
+}
+
+/** Scan the manifests as they exist at a git ref, without checking it out. */
+async function scanRef(ref: string, registry: ScannerRegistry): Promise<SnapshotEntry[]> {
+  const listed = await runCaptureChecked("git", ["ls-tree", "-r", "--name-only", ref]);
+  const entries: SnapshotEntry[] = [];
+  for (const file of trimLines(listed.stdout)) {
+    const scanner = registry.forFile(file);
+    if (!scanner) continue;
+    const shown = await runCaptureChecked("git", ["show", `${ref}:${file}`]);
+    for (const pkg of await scanner.scan(file, shown.stdout)) {
+      entries.push({ name: pkg.name, version: pkg.version, file: pkg.file });
+    }
+  }
+  return entries;
+}
+
+function byKey(entries: readonly SnapshotEntry[]): Map<string, SnapshotEntry> {
+  return new Map(entries.map((entry) => [`${entry.file} ${entry.name}`, entry]));
+}
+
+/**
+ * `treeupdt diff <old.json> <new.json>` or `treeupdt diff --against <ref>`:
+ * packages added, removed, and version-changed between two scans, for
+ * auditing what a branch or PR changed dependency-wise.
+ */
+export async function runDiff(args: readonly string[]): Promise<void> {
+  let before: SnapshotEntry[];
+  let after: SnapshotEntry[];
+
+  if (args[0] === "--against") {
+    const ref = args[1];
+    if (ref === undefined || args.length > 2) {
+      throw new Error("Usage: treeupdt diff --against <git-ref>");
+    }
+    const config = await loadConfig(".");
+    const registry = defaultScannerRegistry();
+    for (const scanner of (await loadPlugins(config)).scanners) {
+      registry.register(scanner);
+    }
+    // Separate registry for the ref: scanners may carry per-tree state
+    // (workspace versions) that must not leak between the two sides.
+    const refRegistry = defaultScannerRegistry();
+    for (const scanner of (await loadPlugins(config)).scanners) {
+      refRegistry.register(scanner);
+    }
+    before = await scanRef(ref, refRegistry);
+    after = await scanTree(".", registry, config.global.excludePaths ?? []);
+  } else {
+    const [oldPath, newPath] = args;
+    if (oldPath === undefined || newPath === undefined || args.length > 2) {
+      throw new Error("Usage: treeupdt diff <old.json> <new.json> | treeupdt diff --against <ref>");
+    }
+    before = parseSnapshot(JSON.parse(await Deno.readTextFile(oldPath)), oldPath);
+    after = parseSnapshot(JSON.parse(await Deno.readTextFile(newPath)), newPath);
+  }
+
+  const oldByKey = byKey(before);
+  const newByKey = byKey(after);
+
+  let added = 0;
+  let removed = 0;
+  let changed = 0;
+  for (const [key, entry] of newByKey) {
+    const previous = oldByKey.get(key);
+    if (previous === undefined) {
+      console.log(`+ ${entry.name} ${entry.version} (${entry.file})`);
+      added += 1;
+    } else if (previous.version !== entry.version) {
+      console.log(`~ ${entry.name} ${previous.version} -> ${entry.version} (${entry.file})`);
+      changed += 1;
+    }
+  }
+  for (const [key, entry] of oldByKey) {
+    if (!newByKey.has(key)) {
+      console.log(`- ${entry.name} ${entry.version} (${entry.file})`);
+      removed += 1;
+    }
+  }
+
+  if (added === 0 && removed === 0 && changed === 0) {
+    console.log("No dependency changes");
+  } else {
+    console.log(`${added} added, ${removed} removed, ${changed} changed`);
+  }
+}
@@ -0,0 +1,176 @@
+import { isIgnoreActive } from "../annotations.ts";
+import {
+  ConfigTree,
+  effectivePinVersion,
+  effectivePreferredSources,
+  effectiveStrategy,
+  loadConfig,
+  matchGroup,
+} from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { matchesPackageName, parsePathSpec } from "../select.ts";
+import { classifyChange, isNewerVersion } from "../semverRange.ts";
+import { defaultSourceRegistry, type VersionInfo } from "../sources.ts";
+import { allowsLevel, selectTarget } from "../strategy.ts";
+import type { Package, Strategy } from "../types.ts";
+import { extractVersion } from "../versionTemplate.ts";
+
+/** Cap on per-version lines so huge histories stay readable. */
+const maxVersionLines = 20;
+
+function skipReason(
+  current: string,
+  version: VersionInfo,
+  strategy: Strategy,
+): string | null {
+  if (version.prerelease === true) return "prerelease";
+  if (version.yanked === true) return "yanked";
+  if (!isNewerVersion(current, version.version)) return null;
+  const level = classifyChange(current, version.version);
+  if (!allowsLevel(strategy, level)) {
+    return `${level ?? "unclassifiable"} change blocked by strategy ${strategy}`;
+  }
+  return null;
+}
+
+async function explainPackage(pkg: Package, configTree: ConfigTree): Promise<void> {
+  console.log(`${pkg.name} (${pkg.file})`);
+  console.log(`  current: ${pkg.version}`);
+
+  const config = await configTree.forFile(pkg.file);
+  const group = matchGroup(config, pkg.name, pkg.file, pkg.sourceHints.map((h) => h.source));
+  if (group !== undefined) console.log(`  group: ${group}`);
+
+  const pin = effectivePinVersion(config, pkg.name);
+  if (pin !== undefined) {
+    console.log(`  pin: ${pin} -> no source is consulted; the pin fixes the wanted version`);
+    if (pkg.version !== pin) {
+      console.log(`  note: manifest has ${pkg.version}, drifted from the pin`);
+    }
+    return;
+  }
+
+  if (pkg.annotation !== undefined && isIgnoreActive(pkg.annotation)) {
+    const reason = pkg.annotation.reason !== undefined ? `: ${pkg.annotation.reason}` : "";
+    console.log(`  annotation: ignored${reason} -> no update will be proposed`);
+    return;
+  }
+
+  let strategy = effectiveStrategy(config, pkg.name, pkg.fileType, group);
+  console.log(`  strategy: ${strategy}`);
+  if (pkg.annotation?.ignoreMajor === true && strategy === "latest") {
+    strategy = "conservative";
+    console.log("  annotation: ignore-major -> strategy tightened to conservative");
+  }
+
+  if (pkg.eolProduct !== undefined) {
+    console.log(`  source: endoflife.date (product ${pkg.eolProduct}); versions are not compared`);
+    return;
+  }
+  if (pkg.sourceHints.length === 0) {
+    console.log("  sources: none -> nothing to consult");
+    return;
+  }
+
+  const preferred = effectivePreferredSources(config, pkg.name);
+  if (preferred !== undefined) {
+    console.log(
+      `  preferred-sources: ${preferred.join(" -> ")} ` +
+        "(later sources are only consulted when earlier ones fail)",
+    );
+  }
+
+  const extractPattern = config.packages[pkg.name]?.extractVersion;
+  const sources = defaultSourceRegistry(config);
+  for (const hint of pkg.sourceHints) {
+    const source = sources.get(hint.source);
+    if (!source) {
+      console.log(`  ${hint.source} ${hint.identifier}: no source registered`);
+      continue;
+    }
+    let versions: VersionInfo[];
+    try {
+      versions = await source.listVersions(hint.identifier);
+    } catch (err) {
+      console.log(
+        `  ${hint.source} ${hint.identifier}: failed: ${
+          err instanceof Error ? err.message : err
+        }`,
+      );
+      continue;
+    }
+    if (extractPattern !== undefined) {
+      const before = versions.length;
+      versions = versions.flatMap((v) => {
+        const extracted = extractVersion(extractPattern, v.version);
+        return extracted === null ? [] : [{ ...v, version: extracted }];
+      });
+      console.log(
+        `  extract-version: ${extractPattern} dropped ${before - versions.length} ` +
+          `of ${before} tags`,
+      );
+    }
+    console.log(`  ${hint.source} ${hint.identifier}: ${versions.length} versions`);
+
+    const newer = versions.filter((v) => isNewerVersion(pkg.version, v.version));
+    let printed = 0;
+    for (const version of newer) {
+      if (printed === maxVersionLines) {
+        console.log(`    ... and ${newer.length - printed} more newer versions`);
+        break;
+      }
+      const reason = skipReason(pkg.version, version, strategy);
+      console.log(`    ${version.version}: ${reason ?? "eligible"}`);
+      printed += 1;
+    }
+
+    const target = selectTarget(pkg.version, versions, strategy);
+    if (target !== null) {
+      console.log(
+        `  target: ${target.version} (${classifyChange(pkg.version, target.version) ?? "?"})`,
+      );
+    } else if (newer.length > 0) {
+      console.log(`  target: none; newer versions exist but the strategy rules them out`);
+    } else {
+      console.log("  target: none; already at the newest version");
+    }
+  }
+}
+
+/**
+ * `treeupdt explain <file>:<package>` (or a bare package name): walk through
+ * the same decisions the check pipeline makes -- sources consulted, versions
+ * found, what strategy/pins/annotations filtered out and why, and the final
+ * proposed target. For debugging surprising check results.
+ */
+export async function runExplain(args: readonly string[]): Promise<void> {
+  const arg = args[0];
+  if (arg === undefined || args.length > 1) {
+    throw new Error("Usage: treeupdt explain <path>:<package> | treeupdt explain <package>");
+  }
+  const spec = parsePathSpec(arg);
+  // A bare argument names a package, not a path: `explain dependencies-react`.
+  const packageName = spec.packageName ?? spec.pattern;
+  const pattern = spec.packageName !== null ? spec.pattern : "";
+
+  const rootConfig = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(rootConfig)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = (await scanTree(".", registry, rootConfig.global.excludePaths ?? []))
+    .filter((pkg) =>
+      matchesPackageName(packageName, pkg) &&
+      (pattern === "" || pkg.file === pattern || pkg.file.startsWith(`${pattern}/`))
+    );
+  if (packages.length === 0) {
+    throw new Error(`No scanned package matches ${arg}`);
+  }
+
+  const configTree = new ConfigTree(".", rootConfig);
+  for (const [i, pkg] of packages.entries()) {
+    if (i > 0) console.log();
+    await explainPackage(pkg, configTree);
+  }
+}
@@ -0,0 +1,37 @@
+import { historyFileName, readHistory } from "../history.ts";
+
+/**
+ * `treeupdt history [--package X]`: print the journal of updates treeupdt
+ * applied to this tree, oldest first. The journal is written by `update`,
+ * `apply`, and pin enforcement; edits from one run share a transaction id.
+ */
+export async function runHistory(args: readonly string[]): Promise<void> {
+  let packageName: string | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--package") {
+      packageName = args[i + 1];
+      if (packageName === undefined) throw new Error("Missing value for --package");
+      i += 1;
+    } else {
+      throw new Error("Usage: treeupdt history [--package X]");
+    }
+  }
+
+  const entries = (await readHistory())
+    .filter((entry) => packageName === undefined || entry.name === packageName);
+  if (entries.length === 0) {
+    console.log(
+      packageName === undefined
+        ? `No recorded updates in ${historyFileName}`
+        : `No recorded updates for ${packageName}`,
+    );
+    return;
+  }
+  for (const entry of entries) {
+    const sha = entry.commitSha !== undefined ? ` commit ${entry.commitSha.slice(0, 12)}` : "";
+    console.log(
+      `${entry.timestamp} [${entry.transactionId}] ${entry.name} ` +
+        `${entry.oldVersion} -> ${entry.newVersion} (${entry.file})${sha}`,
+    );
+  }
+}
@@ -0,0 +1,154 @@
+import { assertRecord } from "../../updater/assert.ts";
+import { fileExists } from "../../updater/fs.ts";
+import { type JsonValue, readJsonObjectFile, writeJsonFile } from "../../updater/jsonFile.ts";
+import { annotationFromLine } from "../annotations.ts";
+import { configFileName, loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { matchesPackageName, parsePathSpec } from "../select.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { Package } from "../types.ts";
+
+function usage(): never {
+  throw new Error(
+    "Usage: treeupdt ignore <path-spec> [--until YYYY-MM-DD] [--reason text] [--config]",
+  );
+}
+
+/** `//` for go.mod, `#` for everything else that supports line comments. */
+function commentToken(fileType: string): string {
+  return fileType === "go" ? "//" : "#";
+}
+
+function directive(until: string | undefined, reason: string | undefined): string {
+  const parts = [until !== undefined ? `ignore-until=${until}` : "ignore"];
+  if (reason !== undefined) {
+    parts.push(`reason="${reason.replaceAll('"', "'")}"`);
+  }
+  return `treeupdt: ${parts.join(" ")}`;
+}
+
+async function annotateInFile(
+  file: string,
+  packages: readonly Package[],
+  until: string | undefined,
+  reason: string | undefined,
+): Promise<void> {
+  const content = await Deno.readTextFile(file);
+  const lines = content.split("\n");
+  let touched = 0;
+  for (const pkg of packages) {
+    if (pkg.span === undefined) {
+      console.log(`${pkg.name}: scanner recorded no span; use --config instead`);
+      continue;
+    }
+    const index = pkg.span.line - 1;
+    const line = lines[index];
+    if (line === undefined) {
+      console.log(`${pkg.name}: ${file}:${pkg.span.line} no longer exists; re-scan`);
+      continue;
+    }
+    if (annotationFromLine(line) !== null) {
+      console.log(`${pkg.name}: ${file}:${pkg.span.line} is already annotated`);
+      continue;
+    }
+    lines[index] = `${line} ${commentToken(pkg.fileType)} ${directive(until, reason)}`;
+    console.log(`Annotated ${pkg.name} at ${file}:${pkg.span.line}`);
+    touched += 1;
+  }
+  if (touched > 0) {
+    const transaction = new FileTransaction();
+    transaction.stage(file, lines.join("\n"));
+    await transaction.commit();
+  }
+}
+
+/** Add the names to `global.deny-packages` in `.treeupdt.json`. */
+async function ignoreViaConfig(names: readonly string[]): Promise<void> {
+  const data: Record<string, JsonValue> = (await fileExists(configFileName))
+    ? await readJsonObjectFile(configFileName)
+    : {};
+  const global = data["global"] ?? {};
+  assertRecord(global, `${configFileName}.global`);
+  const existing = global["deny-packages"];
+  const deny = Array.isArray(existing) ? [...existing] : [];
+  for (const name of names) {
+    if (!deny.includes(name)) deny.push(name);
+  }
+  data["global"] = { ...global, "deny-packages": deny };
+  await writeJsonFile(configFileName, data);
+  console.log(`Added ${names.join(", ")} to global.deny-packages in ${configFileName}`);
+}
+
+/**
+ * `treeupdt ignore <path-spec> [--until DATE] [--reason text] [--config]`:
+ * write the `# treeupdt: ignore` annotation onto the manifest line the
+ * scanner recorded, or (with `--config`, and always for JSON manifests that
+ * can't carry comments) a `deny-packages` config entry. `--until`/`--reason`
+ * only apply to annotations; config denies are unconditional. There is no
+ * per-version-pattern form because the annotation grammar has none; use
+ * `extract-version` or a pin for that.
+ */
+export async function runIgnore(args: readonly string[]): Promise<void> {
+  let spec: string | undefined;
+  let until: string | undefined;
+  let reason: string | undefined;
+  let toConfig = false;
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--until") {
+      until = args[i + 1];
+      if (until === undefined || !Number.isFinite(Date.parse(until))) {
+        throw new Error(`Invalid --until date: ${until ?? "<missing>"}`);
+      }
+      i += 1;
+    } else if (arg === "--reason") {
+      reason = args[i + 1];
+      if (reason === undefined) usage();
+      i += 1;
+    } else if (arg === "--config") {
+      toConfig = true;
+    } else if (arg !== undefined && spec === undefined) {
+      spec = arg;
+    } else {
+      usage();
+    }
+  }
+  if (spec === undefined) usage();
+  if (toConfig && (until !== undefined || reason !== undefined)) {
+    throw new Error("--until/--reason need an annotation; they don't combine with --config");
+  }
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const parsed = parsePathSpec(spec);
+  const packageName = parsed.packageName ?? parsed.pattern;
+  const matches = (await scanTree(".", registry, config.global.excludePaths ?? []))
+    .filter((pkg) =>
+      matchesPackageName(packageName, pkg) &&
+      (parsed.packageName === null || pkg.file === parsed.pattern)
+    );
+  if (matches.length === 0) {
+    throw new Error(`No scanned package matches ${spec}`);
+  }
+
+  // JSON manifests can't carry comments, so those always go through config.
+  const annotatable = matches.filter((pkg) => pkg.fileType !== "npm");
+  const configOnly = toConfig ? matches : matches.filter((pkg) => pkg.fileType === "npm");
+
+  if (!toConfig) {
+    const byFile = new Map<string, Package[]>();
+    for (const pkg of annotatable) {
+      byFile.set(pkg.file, [...(byFile.get(pkg.file) ?? []), pkg]);
+    }
+    for (const [file, filePackages] of byFile) {
+      await annotateInFile(file, filePackages, until, reason);
+    }
+  }
+  if (configOnly.length > 0) {
+    await ignoreViaConfig([...new Set(configOnly.map((pkg) => pkg.name))]);
+  }
+}
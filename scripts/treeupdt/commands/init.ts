@@ -0,0 +1,103 @@
+import { dirname } from "node:path";
+import { fileExists } from "../../updater/fs.ts";
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+
+function githubWorkflow(ecosystems: readonly string[]): string {
+  return `# Scheduled dependency updates via treeupdt.
+# Detected ecosystems: ${ecosystems.join(", ") || "none"}
+name: treeupdt
+
+on:
+  schedule:
+    - cron: "0 6 * * 1"
+  workflow_dispatch:
+
+jobs:
+  update:
+    runs-on: ubuntu-latest
+    permissions:
+      contents: write
+      pull-requests: write
+    steps:
+      - uses: actions/checkout@v4
+      - uses: denoland/setup-deno@v2
+        with:
+          deno-version: v2.x
+      - uses: actions/cache@v4
+        with:
+          path: ~/.cache/treeupdt
+          key: treeupdt-\${{ runner.os }}
+      - name: Plan updates
+        run: deno run -A scripts/treeupdt/cli.ts plan --out plan.json
+        env:
+          GITHUB_TOKEN: \${{ secrets.GITHUB_TOKEN }}
+      - name: Apply plan
+        run: deno run -A scripts/treeupdt/cli.ts apply plan.json
+      - uses: peter-evans/create-pull-request@v6
+        with:
+          branch: treeupdt/updates
+          title: "chore: dependency updates"
+          commit-message: "chore: dependency updates"
+`;
+}
+
+function gitlabPipeline(ecosystems: readonly string[]): string {
+  return `# Scheduled dependency updates via treeupdt.
+# Detected ecosystems: ${ecosystems.join(", ") || "none"}
+treeupdt:
+  image: denoland/deno:latest
+  rules:
+    - if: $CI_PIPELINE_SOURCE == "schedule"
+  cache:
+    key: treeupdt
+    paths:
+      - .treeupdt-cache/
+  variables:
+    TREEUPDT_CACHE_DIR: .treeupdt-cache
+    # A project access token with write_repository, for pushing the branch.
+    GITLAB_TOKEN: $TREEUPDT_GITLAB_TOKEN
+  script:
+    - deno run -A scripts/treeupdt/cli.ts plan --out plan.json
+    - deno run -A scripts/treeupdt/cli.ts apply plan.json
+    - git checkout -B treeupdt/updates
+    - git commit -am "chore: dependency updates" || exit 0
+    - git push -o merge_request.create origin treeupdt/updates
+`;
+}
+
+/**
+ * `treeupdt init ci --github|--gitlab`: write a ready-to-run scheduled
+ * update workflow -- weekly plan+apply, PR/MR creation, token wiring, and
+ * cache persistence -- annotated with the ecosystems a quick scan found.
+ */
+export async function runInit(args: readonly string[]): Promise<void> {
+  const usage = "Usage: treeupdt init ci --github|--gitlab";
+  if (args[0] !== "ci" || args.length !== 2) {
+    throw new Error(usage);
+  }
+  const target = args[1];
+  if (target !== "--github" && target !== "--gitlab") {
+    throw new Error(usage);
+  }
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = await scanTree(".", registry, config.global.excludePaths ?? []);
+  const ecosystems = [...new Set(packages.map((pkg) => pkg.fileType))].sort();
+
+  const path = target === "--github" ? ".github/workflows/treeupdt.yml" : ".gitlab-ci.yml";
+  if (await fileExists(path)) {
+    throw new Error(`${path} already exists; refusing to overwrite`);
+  }
+  await Deno.mkdir(dirname(path), { recursive: true });
+  await Deno.writeTextFile(
+    path,
+    target === "--github" ? githubWorkflow(ecosystems) : gitlabPipeline(ecosystems),
+  );
+  console.log(`Wrote ${path} (ecosystems: ${ecosystems.join(", ") || "none"})`);
+}
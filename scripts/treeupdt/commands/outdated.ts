@@ -0,0 +1,74 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { renderTable } from "../output/table.ts";
+import { isStderrTerminal } from "../progress.ts";
+import { parsePathSpec, type PathSpec } from "../select.ts";
+import type { UpdateEntry } from "../types.ts";
+
+/** Compact age of an ISO timestamp for table cells, e.g. `3mo`. */
+function shortAge(iso: string): string {
+  const elapsed = Date.now() - Date.parse(iso);
+  if (!Number.isFinite(elapsed) || elapsed < 0) return "";
+  const days = Math.floor(elapsed / 86_400_000);
+  if (days < 31) return `${days}d`;
+  const months = Math.floor(days / 30);
+  if (months < 12) return `${months}mo`;
+  return `${Math.floor(months / 12)}y`;
+}
+
+function row(entry: UpdateEntry): string[] {
+  return [
+    entry.name,
+    entry.file,
+    entry.current,
+    entry.latest ?? "-",
+    // As in the CSV output: the latest we surface is already the newest
+    // stable release, so `stable` mirrors it unless the strategy blocks it.
+    entry.blockedByStrategy === true ? "-" : entry.latest ?? "-",
+    entry.semverLevel ?? (entry.blockedByStrategy === true ? "blocked" : ""),
+    entry.latestPublishedAt !== undefined ? shortAge(entry.latestPublishedAt) : "",
+  ];
+}
+
+/**
+ * `treeupdt outdated [path[:package]...]`: scan+check and print one aligned
+ * table of everything with a newer release, for skimming large repos.
+ */
+export async function runOutdated(args: readonly string[]): Promise<void> {
+  let jobs: number | undefined;
+  const selectors: PathSpec[] = [];
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--jobs") {
+      jobs = Number(args[i + 1]);
+      if (!Number.isInteger(jobs) || jobs < 1) {
+        throw new Error(`Invalid --jobs value: ${args[i + 1]}`);
+      }
+      i += 1;
+    } else if (arg !== undefined) {
+      selectors.push(parsePathSpec(arg));
+    }
+  }
+
+  const report = await runCheckPipeline(".", {
+    ...(jobs !== undefined ? { jobs } : {}),
+    selectors,
+    progress: isStderrTerminal(),
+    signal: interruptSignal(),
+  });
+
+  const outdated = report.entries
+    .filter((entry) => entry.updateAvailable === true || entry.blockedByStrategy === true)
+    .sort((a, b) => a.file.localeCompare(b.file) || a.name.localeCompare(b.name));
+
+  if (outdated.length === 0) {
+    console.log(`All ${report.entries.length} checked packages are up to date`);
+    return;
+  }
+
+  console.log(renderTable(
+    ["Package", "File", "Current", "Latest", "Stable", "Level", "Age"],
+    outdated.map(row),
+  ));
+  console.log(`\n${outdated.length} outdated of ${report.entries.length} checked`);
+}
@@ -0,0 +1,66 @@
+import { assertRecord, isRecord } from "../../updater/assert.ts";
+import { fileExists } from "../../updater/fs.ts";
+import { type JsonValue, readJsonObjectFile, writeJsonFile } from "../../updater/jsonFile.ts";
+import { configFileName } from "../config.ts";
+
+async function readConfigFile(): Promise<Record<string, JsonValue>> {
+  if (!(await fileExists(configFileName))) return {};
+  return await readJsonObjectFile(configFileName);
+}
+
+function packagesTable(data: Record<string, JsonValue>): Record<string, JsonValue> {
+  const existing = data["packages"];
+  if (existing === undefined) {
+    const packages: Record<string, JsonValue> = {};
+    data["packages"] = packages;
+    return packages;
+  }
+  assertRecord(existing, `${configFileName}.packages`);
+  return existing;
+}
+
+export async function runPin(args: readonly string[]): Promise<void> {
+  const [packageName, version] = args;
+  if (!packageName || !version) {
+    throw new Error("Usage: treeupdt pin <package> <version>");
+  }
+
+  const data = await readConfigFile();
+  const packages = packagesTable(data);
+  const entry = packages[packageName];
+  const updated: Record<string, JsonValue> = isRecord(entry) ? { ...entry } : {};
+  updated["pin-version"] = version;
+  packages[packageName] = updated;
+
+  await writeJsonFile(configFileName, data);
+  console.log(`Pinned ${packageName} to ${version} in ${configFileName}`);
+}
+
+export async function runUnpin(args: readonly string[]): Promise<void> {
+  const [packageName] = args;
+  if (!packageName) {
+    throw new Error("Usage: treeupdt unpin <package>");
+  }
+
+  const data = await readConfigFile();
+  const packages = data["packages"];
+  const entry = isRecord(packages) ? packages[packageName] : undefined;
+  if (!isRecord(packages) || !isRecord(entry) || entry["pin-version"] === undefined) {
+    console.log(`${packageName} is not pinned`);
+    return;
+  }
+
+  const updated: Record<string, JsonValue> = { ...entry };
+  delete updated["pin-version"];
+  if (Object.keys(updated).length === 0) {
+    delete packages[packageName];
+  } else {
+    packages[packageName] = updated;
+  }
+  if (Object.keys(packages).length === 0) {
+    delete data["packages"];
+  }
+
+  await writeJsonFile(configFileName, data);
+  console.log(`Unpinned ${packageName}`);
+}
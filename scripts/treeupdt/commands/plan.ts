@@ -0,0 +1,100 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { appendHistory, newTransactionId } from "../history.ts";
+import { withLock } from "../lock.ts";
+import { buildPlan, parsePlan } from "../plan.ts";
+import { defaultUpdaterRegistry } from "../updaters.ts";
+
+/** `treeupdt plan [--out file]`: emit the edits a run would make as JSON. */
+export async function runPlan(args: readonly string[]): Promise<void> {
+  let out: string | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--out") {
+      out = args[i + 1];
+      if (out === undefined) throw new Error("Missing value for --out");
+      i += 1;
+    } else {
+      throw new Error(`Unknown plan argument: ${args[i]}`);
+    }
+  }
+
+  const report = await runCheckPipeline(".");
+  const plan = buildPlan(".", report);
+  const json = `${JSON.stringify(plan, null, 2)}\n`;
+  if (out !== undefined) {
+    await Deno.writeTextFile(out, json);
+    console.log(
+      `Planned ${plan.updates.length} update${plan.updates.length === 1 ? "" : "s"} in ${out}`,
+    );
+  } else {
+    console.log(json.trimEnd());
+  }
+}
+
+/**
+ * `treeupdt apply <plan.json>`: execute a previously reviewed plan. Each
+ * edit's from-version must still match the manifest, so a plan can't silently
+ * apply on top of a tree that moved since it was produced.
+ */
+export async function runApply(args: readonly string[]): Promise<void> {
+  const noSync = args.includes("--no-sync");
+  const positional = args.filter((arg) => arg !== "--no-sync");
+  const path = positional[0];
+  if (path === undefined || positional.length > 1) {
+    throw new Error("Usage: treeupdt apply <plan.json> [--no-sync]");
+  }
+
+  const plan = parsePlan(JSON.parse(await Deno.readTextFile(path)), path);
+  const updaters = defaultUpdaterRegistry();
+  const signal = interruptSignal();
+  const transactionId = newTransactionId();
+
+  await withLock(".", async () => {
+    let applied = 0;
+    let failed = 0;
+    for (const update of plan.updates) {
+      if (signal.aborted) {
+        console.log("Interrupted; remaining plan entries not applied");
+        break;
+      }
+      const updater = updaters.forFile(update.file);
+      if (!updater) {
+        console.log(`${update.package}: unsupported file ${update.file}`);
+        failed += 1;
+        continue;
+      }
+      try {
+        const outcome = await updater.apply(update.file, update.identifier, update.toVersion, {
+          sync: !noSync,
+          signal,
+        });
+        if (outcome.oldVersion !== update.fromVersion) {
+          console.log(
+            `${update.package}: manifest had ${outcome.oldVersion}, plan expected ` +
+              `${update.fromVersion} (applied anyway; re-plan to avoid this)`,
+          );
+        }
+        console.log(
+          `Applied: ${update.package} ${update.fromVersion} -> ${update.toVersion} ` +
+            `in ${update.file}`,
+        );
+        await appendHistory({
+          timestamp: new Date().toISOString(),
+          file: update.file,
+          name: update.package,
+          oldVersion: outcome.oldVersion,
+          newVersion: update.toVersion,
+          transactionId,
+        });
+        applied += 1;
+      } catch (err) {
+        console.log(`${update.package}: ${err instanceof Error ? err.message : err}`);
+        failed += 1;
+      }
+    }
+    console.log(`${applied} applied, ${failed} failed, of ${plan.updates.length} planned`);
+    if (failed > 0) {
+      Deno.exit(1);
+    }
+  });
+}
@@ -0,0 +1,53 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { renderReportHtml, renderReportJson, renderReportMarkdown } from "../output/report.ts";
+import { isStderrTerminal } from "../progress.ts";
+
+const reportFormats = ["md", "html", "json"] as const;
+
+type ReportFormat = (typeof reportFormats)[number];
+
+/**
+ * `treeupdt report [--format md|html|json] [--out file]`: a single freshness
+ * document combining check results, EOL and version-health data, and summary
+ * statistics per ecosystem, for periodic dependency reviews.
+ */
+export async function runReport(args: readonly string[]): Promise<void> {
+  let format: ReportFormat = "md";
+  let out: string | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--format") {
+      const value = args[i + 1];
+      if (value === undefined || !(reportFormats as readonly string[]).includes(value)) {
+        throw new Error(`Invalid --format value: ${value ?? "<missing>"} (expected md|html|json)`);
+      }
+      format = value as ReportFormat;
+      i += 1;
+    } else if (arg === "--out") {
+      out = args[i + 1];
+      if (out === undefined) throw new Error("Missing value for --out");
+      i += 1;
+    } else {
+      throw new Error(`Unknown report argument: ${arg}`);
+    }
+  }
+
+  const report = await runCheckPipeline(".", {
+    progress: isStderrTerminal(),
+    signal: interruptSignal(),
+  });
+
+  const rendered = format === "html"
+    ? renderReportHtml(report.entries)
+    : format === "json"
+    ? renderReportJson(report.entries)
+    : renderReportMarkdown(report.entries);
+
+  if (out !== undefined) {
+    await Deno.writeTextFile(out, `${rendered.trimEnd()}\n`);
+    console.log(`Wrote ${format} report to ${out}`);
+  } else {
+    console.log(rendered);
+  }
+}
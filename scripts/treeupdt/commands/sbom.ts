@@ -0,0 +1,56 @@
+import { basename, resolve } from "node:path";
+import { loadConfig } from "../config.ts";
+import { renderCycloneDx, renderSpdx } from "../output/sbom.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+
+const formats = ["cyclonedx", "spdx"] as const;
+
+type Format = (typeof formats)[number];
+
+/**
+ * `treeupdt sbom --format cyclonedx|spdx [--out file]`: a software bill of
+ * materials built from scan results -- versions, purls, and source URLs for
+ * every package across all ecosystems. No network access; this is the
+ * declared inventory, not a resolved one.
+ */
+export async function runSbom(args: readonly string[]): Promise<void> {
+  let format: Format = "cyclonedx";
+  let out: string | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--format") {
+      const value = args[i + 1];
+      if (value === undefined || !(formats as readonly string[]).includes(value)) {
+        throw new Error(
+          `Invalid --format value: ${value ?? "<missing>"} (expected cyclonedx|spdx)`,
+        );
+      }
+      format = value as Format;
+      i += 1;
+    } else if (args[i] === "--out") {
+      out = args[i + 1];
+      if (out === undefined) throw new Error("Missing value for --out");
+      i += 1;
+    } else {
+      throw new Error("Usage: treeupdt sbom [--format cyclonedx|spdx] [--out file]");
+    }
+  }
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = await scanTree(".", registry, config.global.excludePaths ?? []);
+
+  const rendered = format === "cyclonedx"
+    ? renderCycloneDx(packages)
+    : renderSpdx(packages, basename(resolve(".")));
+
+  if (out !== undefined) {
+    await Deno.writeTextFile(out, `${rendered}\n`);
+    console.log(`Wrote ${format} SBOM with ${packages.length} packages to ${out}`);
+  } else {
+    console.log(rendered);
+  }
+}
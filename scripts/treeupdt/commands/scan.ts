@@ -0,0 +1,18 @@
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { parsePathSpec, selectPackages } from "../select.ts";
+
+export async function runScan(args: readonly string[]): Promise<void> {
+  const specs = args.map(parsePathSpec);
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = selectPackages(
+    await scanTree(".", registry, config.global.excludePaths ?? []),
+    specs,
+  );
+  console.log(JSON.stringify(packages, null, 2));
+}
@@ -0,0 +1,55 @@
+import { loadConfig } from "../config.ts";
+import { renderTable } from "../output/table.ts";
+import { defaultSourceRegistry, type VersionInfo } from "../sources.ts";
+
+/** Cap on printed versions so prolific crates stay readable. */
+const maxResults = 40;
+
+function flags(version: VersionInfo): string {
+  const set: string[] = [];
+  if (version.prerelease === true) set.push("prerelease");
+  if (version.yanked === true) set.push("yanked");
+  if (version.deprecated !== undefined) set.push("deprecated");
+  return set.join(", ");
+}
+
+/**
+ * `treeupdt search <source> <name>`: ad-hoc version lookup straight through a
+ * Source implementation -- `search crates serde`, `search github denoland/deno`
+ * -- printing dates, flags, and download counts where the registry has them.
+ */
+export async function runSearch(args: readonly string[]): Promise<void> {
+  const [sourceType, identifier] = args;
+  if (sourceType === undefined || identifier === undefined || args.length > 2) {
+    throw new Error("Usage: treeupdt search <source> <name>");
+  }
+
+  const config = await loadConfig(".");
+  const sources = defaultSourceRegistry(config);
+  const source = sources.get(sourceType);
+  if (!source) {
+    throw new Error(
+      `No source registered for ${sourceType}; available: ${sources.types.join(", ")}`,
+    );
+  }
+
+  const versions = await source.listVersions(identifier);
+  if (versions.length === 0) {
+    console.log(`No versions found for ${identifier} on ${sourceType}`);
+    return;
+  }
+
+  const shown = versions.slice(0, maxResults);
+  console.log(renderTable(
+    ["Version", "Published", "Flags", "Downloads"],
+    shown.map((version) => [
+      version.version,
+      version.publishedAt?.slice(0, 10) ?? "",
+      flags(version),
+      version.downloads !== undefined ? String(version.downloads) : "",
+    ]),
+  ));
+  if (versions.length > shown.length) {
+    console.log(`... and ${versions.length - shown.length} older versions`);
+  }
+}
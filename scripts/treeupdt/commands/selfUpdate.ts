@@ -0,0 +1,92 @@
+import { basename, dirname, join } from "node:path";
+import { loadConfig } from "../config.ts";
+import { fetchText, fetchWithRetry, HttpStatusError } from "../http.ts";
+import { isNewerVersion } from "../semverRange.ts";
+import { defaultSourceRegistry } from "../sources.ts";
+import { selfRepo, version } from "../version.ts";
+
+function assetName(): string {
+  return `treeupdt-${Deno.build.os}-${Deno.build.arch}`;
+}
+
+async function sha256Hex(bytes: Uint8Array): Promise<string> {
+  const digest = await crypto.subtle.digest("SHA-256", bytes as BufferSource);
+  return [...new Uint8Array(digest)].map((b) => b.toString(16).padStart(2, "0")).join("");
+}
+
+/** Expected digest for the asset from the release's SHA256SUMS file. */
+function expectedDigest(sums: string, asset: string): string | null {
+  for (const line of sums.split("\n")) {
+    const [digest, name] = line.trim().split(/\s+\*?/);
+    if (digest !== undefined && name === asset) return digest.toLowerCase();
+  }
+  return null;
+}
+
+/**
+ * `treeupdt self-update [--check]`: query our own GitHub releases through the
+ * regular github source, and when a newer stable version exists, download the
+ * platform binary, verify it against the release's SHA256SUMS, and swap it
+ * into place atomically. Running from source (via deno) only supports
+ * `--check`; there is no binary to replace then.
+ */
+export async function runSelfUpdate(args: readonly string[]): Promise<void> {
+  const checkOnly = args.includes("--check");
+  if (args.some((arg) => arg !== "--check")) {
+    throw new Error("Usage: treeupdt self-update [--check]");
+  }
+
+  const config = await loadConfig(".");
+  const source = defaultSourceRegistry(config).get("github");
+  if (!source) throw new Error("No github source registered");
+  const versions = await source.listVersions(selfRepo);
+  const latest = versions.find((v) => v.prerelease !== true && v.yanked !== true);
+  if (latest === undefined) {
+    throw new Error(`No stable releases found for ${selfRepo}`);
+  }
+  if (!isNewerVersion(version, latest.version)) {
+    console.log(`Already up to date (${version})`);
+    return;
+  }
+  console.log(`New version available: ${version} -> ${latest.version}`);
+  if (checkOnly) return;
+
+  const execPath = Deno.execPath();
+  if (basename(execPath).startsWith("deno")) {
+    throw new Error(
+      "Running from source via deno; update the checkout instead of self-updating",
+    );
+  }
+
+  const base = `https://github.com/${selfRepo}/releases/download/v${latest.version}`;
+  const asset = assetName();
+  let sums: string;
+  try {
+    sums = await fetchText(`${base}/SHA256SUMS`);
+  } catch (err) {
+    if (err instanceof HttpStatusError && err.status === 404) {
+      throw new Error(`Release v${latest.version} has no SHA256SUMS; refusing unverified update`);
+    }
+    throw err;
+  }
+  const expected = expectedDigest(sums, asset);
+  if (expected === null) {
+    throw new Error(`SHA256SUMS for v${latest.version} has no entry for ${asset}`);
+  }
+
+  const res = await fetchWithRetry(`${base}/${asset}`);
+  if (!res.ok) {
+    throw new Error(`HTTP ${res.status} downloading ${asset}`);
+  }
+  const bytes = new Uint8Array(await res.arrayBuffer());
+  const actual = await sha256Hex(bytes);
+  if (actual !== expected) {
+    throw new Error(`Checksum mismatch for ${asset}: expected ${expected}, got ${actual}`);
+  }
+
+  // Write next to the binary and rename, so a crash never leaves half a file.
+  const tempPath = join(dirname(execPath), `.${basename(execPath)}.new`);
+  await Deno.writeFile(tempPath, bytes, { mode: 0o755 });
+  await Deno.rename(tempPath, execPath);
+  console.log(`Updated to ${latest.version}`);
+}
@@ -0,0 +1,266 @@
+import { isRecord } from "../../updater/assert.ts";
+import { runCheckPipeline } from "../check.ts";
+import { loadConfig } from "../config.ts";
+import { withLock } from "../lock.ts";
+import { badgeData, shieldsJson } from "../output/badge.ts";
+import { renderReportHtml } from "../output/report.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { defaultUpdaterRegistry } from "../updaters.ts";
+import type { Package } from "../types.ts";
+
+/**
+ * JSON-RPC 2.0 over stdio for editor integrations: one request per line on
+ * stdin, one response per line on stdout. Methods:
+ *
+ * - `scan` `{root?}` — packages with document-relative line numbers, so an
+ *   extension can attach "update available" lenses to manifest lines;
+ * - `check` `{root?}` — the full update report;
+ * - `update` `{file, package, version, sync?}` — apply one bump;
+ * - `badge` `{root?}` — shields.io endpoint payload summarizing outdated counts;
+ * - `shutdown` — exit cleanly.
+ */
+type RpcRequest = Readonly<{
+  id: number | string | null;
+  method: string;
+  params: Readonly<Record<string, unknown>>;
+}>;
+
+const parseErrorCode = -32700;
+const invalidRequestCode = -32600;
+const methodNotFoundCode = -32601;
+const invalidParamsCode = -32602;
+const serverErrorCode = -32000;
+
+function respond(id: number | string | null, result: unknown): void {
+  console.log(JSON.stringify({ jsonrpc: "2.0", id, result }));
+}
+
+function respondError(id: number | string | null, code: number, message: string): void {
+  console.log(JSON.stringify({ jsonrpc: "2.0", id, error: { code, message } }));
+}
+
+function parseRequest(line: string): RpcRequest | null {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(line);
+  } catch {
+    respondError(null, parseErrorCode, "Parse error");
+    return null;
+  }
+  if (!isRecord(parsed) || typeof parsed["method"] !== "string") {
+    respondError(null, invalidRequestCode, "Invalid request");
+    return null;
+  }
+  const id = parsed["id"];
+  const params = parsed["params"];
+  return {
+    id: typeof id === "number" || typeof id === "string" ? id : null,
+    method: parsed["method"],
+    params: isRecord(params) ? params : {},
+  };
+}
+
+/**
+ * 1-based line of the package's version in its manifest. The scanner's span
+ * is authoritative when present; otherwise fall back to a literal scan. Null
+ * when the manifest can't be read or the version isn't present.
+ */
+async function packageLine(root: string, pkg: Package): Promise<number | null> {
+  if (pkg.span !== undefined) return pkg.span.line;
+  let content: string;
+  try {
+    content = await Deno.readTextFile(`${root}/${pkg.file}`);
+  } catch {
+    return null;
+  }
+  const lines = content.split("\n");
+  for (let i = 0; i < lines.length; i += 1) {
+    const line = lines[i];
+    if (line !== undefined && line.includes(pkg.version) && line.includes(pkg.id.name)) {
+      return i + 1;
+    }
+  }
+  return null;
+}
+
+async function handleScan(params: Readonly<Record<string, unknown>>): Promise<unknown> {
+  const root = typeof params["root"] === "string" ? params["root"] : ".";
+  const config = await loadConfig(root);
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = await scanTree(root, registry, config.global.excludePaths ?? []);
+  return await Promise.all(
+    packages.map(async (pkg) => ({
+      ...pkg,
+      line: await packageLine(root, pkg),
+    })),
+  );
+}
+
+async function handleCheck(params: Readonly<Record<string, unknown>>): Promise<unknown> {
+  const root = typeof params["root"] === "string" ? params["root"] : ".";
+  return await runCheckPipeline(root);
+}
+
+async function handleBadge(params: Readonly<Record<string, unknown>>): Promise<unknown> {
+  const root = typeof params["root"] === "string" ? params["root"] : ".";
+  const report = await runCheckPipeline(root);
+  return shieldsJson(badgeData(report.entries));
+}
+
+async function handleUpdate(params: Readonly<Record<string, unknown>>): Promise<unknown> {
+  const file = params["file"];
+  const packageName = params["package"];
+  const version = params["version"];
+  if (typeof file !== "string" || typeof packageName !== "string" || typeof version !== "string") {
+    throw new RpcParamsError("update needs file, package, and version strings");
+  }
+  return await withLock(".", async () => {
+    const updater = defaultUpdaterRegistry().forFile(file);
+    if (!updater) {
+      throw new RpcParamsError(`Unsupported file: ${file}`);
+    }
+    const outcome = await updater.apply(file, packageName, version, {
+      sync: params["sync"] !== false,
+    });
+    return { oldVersion: outcome.oldVersion, newVersion: version };
+  });
+}
+
+class RpcParamsError extends Error {}
+
+async function handle(request: RpcRequest): Promise<boolean> {
+  try {
+    switch (request.method) {
+      case "scan":
+        respond(request.id, await handleScan(request.params));
+        return true;
+      case "check":
+        respond(request.id, await handleCheck(request.params));
+        return true;
+      case "update":
+        respond(request.id, await handleUpdate(request.params));
+        return true;
+      case "badge":
+        respond(request.id, await handleBadge(request.params));
+        return true;
+      case "shutdown":
+        respond(request.id, null);
+        return false;
+      default:
+        respondError(request.id, methodNotFoundCode, `Unknown method: ${request.method}`);
+        return true;
+    }
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    respondError(
+      request.id,
+      err instanceof RpcParamsError ? invalidParamsCode : serverErrorCode,
+      message,
+    );
+    return true;
+  }
+}
+
+/** How long HTTP responses are reused before the tree is re-checked. */
+const httpCacheTtlMs = 60_000;
+
+const httpCache = new Map<
+  string,
+  Readonly<{ body: string; contentType: string; expires: number }>
+>();
+
+async function renderEndpoint(
+  pathname: string,
+): Promise<{ body: string; contentType: string } | null> {
+  switch (pathname) {
+    case "/scan":
+      return {
+        body: `${JSON.stringify(await handleScan({}), null, 2)}\n`,
+        contentType: "application/json",
+      };
+    case "/check":
+      return {
+        body: `${JSON.stringify(await runCheckPipeline("."), null, 2)}\n`,
+        contentType: "application/json",
+      };
+    case "/report":
+      return {
+        body: renderReportHtml((await runCheckPipeline(".")).entries),
+        contentType: "text/html; charset=utf-8",
+      };
+    case "/badge":
+      return {
+        body: `${JSON.stringify(shieldsJson(badgeData((await runCheckPipeline(".")).entries)))}\n`,
+        contentType: "application/json",
+      };
+    default:
+      return null;
+  }
+}
+
+async function handleHttp(req: Request): Promise<Response> {
+  if (req.method !== "GET") {
+    return new Response("method not allowed\n", { status: 405 });
+  }
+  const pathname = new URL(req.url).pathname;
+  const cached = httpCache.get(pathname);
+  if (cached !== undefined && cached.expires > Date.now()) {
+    return new Response(cached.body, { headers: { "Content-Type": cached.contentType } });
+  }
+  try {
+    const rendered = await renderEndpoint(pathname);
+    if (rendered === null) {
+      return new Response("not found; endpoints: /scan /check /report /badge\n", { status: 404 });
+    }
+    httpCache.set(pathname, { ...rendered, expires: Date.now() + httpCacheTtlMs });
+    return new Response(rendered.body, { headers: { "Content-Type": rendered.contentType } });
+  } catch (err) {
+    return new Response(`${err instanceof Error ? err.message : err}\n`, { status: 500 });
+  }
+}
+
+/**
+ * `serve --listen host:port`: read-only REST endpoints over the working tree
+ * (`/scan`, `/check`, `/report`, `/badge`), with responses cached for a
+ * minute so dashboard polling doesn't hammer the sources.
+ */
+function runListen(addr: string): Promise<void> {
+  const colon = addr.lastIndexOf(":");
+  const hostname = colon === -1 ? addr : addr.slice(0, colon);
+  const port = colon === -1 ? NaN : Number(addr.slice(colon + 1));
+  if (hostname === "" || !Number.isInteger(port) || port < 1 || port > 65535) {
+    throw new Error(`Invalid --listen address: ${addr} (expected host:port)`);
+  }
+  const server = Deno.serve({ hostname, port }, handleHttp);
+  return server.finished;
+}
+
+export async function runServe(args: readonly string[]): Promise<void> {
+  if (args[0] === "--listen" && args[1] !== undefined && args.length === 2) {
+    await runListen(args[1]);
+    return;
+  }
+  if (args[0] !== "--stdio" || args.length > 1) {
+    throw new Error("Usage: treeupdt serve --stdio | treeupdt serve --listen host:port");
+  }
+
+  const decoder = new TextDecoder();
+  let buffer = "";
+  for await (const chunk of Deno.stdin.readable) {
+    buffer += decoder.decode(chunk, { stream: true });
+    let newline: number;
+    while ((newline = buffer.indexOf("\n")) !== -1) {
+      const line = buffer.slice(0, newline).trim();
+      buffer = buffer.slice(newline + 1);
+      if (line === "") continue;
+      const request = parseRequest(line);
+      if (request !== null && !(await handle(request))) {
+        return;
+      }
+    }
+  }
+}
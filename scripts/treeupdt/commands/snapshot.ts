@@ -0,0 +1,167 @@
+import { basename } from "node:path";
+import { isRecord } from "../../updater/assert.ts";
+import { fileExists } from "../../updater/fs.ts";
+import { calculateUrlHash } from "../../updater/hash.ts";
+import { readJsonObjectFile, writeJsonFile } from "../../updater/jsonFile.ts";
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, listTreeFiles, type ScannerRegistry, scanTree } from "../scan.ts";
+import { parseFetchFromGithub, parseVersionAttr, resolveRev } from "../updaters/nix.ts";
+
+/** Snapshot file written next to `.treeupdt.json`; meant to be committed. */
+export const lockFileName = "treeupdt.lock";
+
+type LockEntry = Readonly<{
+  file: string;
+  name: string;
+  version: string;
+}>;
+
+async function treeRegistry(): Promise<{ registry: ScannerRegistry; excludes: readonly string[] }> {
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  return { registry, excludes: config.global.excludePaths ?? [] };
+}
+
+async function scanEntries(): Promise<LockEntry[]> {
+  const { registry, excludes } = await treeRegistry();
+  return (await scanTree(".", registry, excludes))
+    .map((pkg) => ({ file: pkg.file, name: pkg.name, version: pkg.version }))
+    .sort((a, b) => a.file.localeCompare(b.file) || a.name.localeCompare(b.name));
+}
+
+function parseLock(data: Record<string, unknown>): LockEntry[] {
+  const packages = data["packages"];
+  if (!Array.isArray(packages)) {
+    throw new Error(`${lockFileName}: expected a packages array`);
+  }
+  return packages.map((item, i) => {
+    if (
+      !isRecord(item) || typeof item["file"] !== "string" ||
+      typeof item["name"] !== "string" || typeof item["version"] !== "string"
+    ) {
+      throw new Error(`${lockFileName}: packages[${i}] needs file, name, and version strings`);
+    }
+    return { file: item["file"], name: item["name"], version: item["version"] };
+  });
+}
+
+/**
+ * `treeupdt snapshot`: record every scanned package and its version in
+ * `treeupdt.lock`. Pair with `verify` on release branches where any
+ * dependency change must be deliberate.
+ */
+export async function runSnapshot(args: readonly string[]): Promise<void> {
+  if (args.length > 0) {
+    throw new Error("Usage: treeupdt snapshot");
+  }
+  const packages = await scanEntries();
+  await writeJsonFile(lockFileName, {
+    version: 1,
+    "generated-at": new Date().toISOString(),
+    packages,
+  });
+  console.log(`Wrote ${packages.length} packages to ${lockFileName}`);
+}
+
+/**
+ * `verify --hashes`: re-prefetch the source of every `package.nix` at its
+ * declared version and compare against the committed hash, catching
+ * hand-edited versions with stale hashes before CI does. Only
+ * `fetchFromGitHub` derivations are covered; nothing in this tree scans
+ * npins/niv pins.
+ */
+async function verifyHashes(): Promise<void> {
+  const manifests = (await listTreeFiles("."))
+    .filter((file) => basename(file) === "package.nix");
+  if (manifests.length === 0) {
+    console.log("No package.nix files to verify");
+    return;
+  }
+
+  let mismatches = 0;
+  let skipped = 0;
+  for (const file of manifests) {
+    const content = await Deno.readTextFile(file);
+    let expected: string;
+    let url: string;
+    try {
+      const version = parseVersionAttr(content);
+      const fetcher = parseFetchFromGithub(content);
+      const rev = resolveRev(fetcher.revTemplate, version);
+      expected = fetcher.hash;
+      url = `https://github.com/${fetcher.owner}/${fetcher.repo}/archive/${rev}.tar.gz`;
+    } catch (err) {
+      console.log(`${file}: skipped: ${err instanceof Error ? err.message : err}`);
+      skipped += 1;
+      continue;
+    }
+    const actual = await calculateUrlHash(url, { unpack: true });
+    if (actual === expected) {
+      console.log(`${file}: hash matches`);
+    } else {
+      console.log(`${file}: MISMATCH: declared ${expected}, prefetched ${actual}`);
+      mismatches += 1;
+    }
+  }
+  if (mismatches > 0) {
+    console.log(`${mismatches} of ${manifests.length} hashes are stale`);
+    Deno.exit(1);
+  }
+  // Skipping everything means nothing got verified; don't report success.
+  if (skipped === manifests.length) {
+    console.log(`All ${manifests.length} package.nix files were skipped; nothing verified`);
+    Deno.exit(1);
+  }
+}
+
+/**
+ * `treeupdt verify [--hashes]`: fail (exit 1) when the tree's packages drift
+ * from the last `treeupdt snapshot`, listing each added, removed, or changed
+ * package. With `--hashes`, instead verify fetcher hashes against their
+ * declared versions.
+ */
+export async function runVerify(args: readonly string[]): Promise<void> {
+  if (args.length === 1 && args[0] === "--hashes") {
+    await verifyHashes();
+    return;
+  }
+  if (args.length > 0) {
+    throw new Error("Usage: treeupdt verify [--hashes]");
+  }
+  if (!await fileExists(lockFileName)) {
+    throw new Error(`${lockFileName} not found; run \`treeupdt snapshot\` first`);
+  }
+  const locked = parseLock(await readJsonObjectFile(lockFileName));
+  const current = await scanEntries();
+
+  const lockedByKey = new Map(locked.map((e) => [`${e.file} ${e.name}`, e]));
+  const currentByKey = new Map(current.map((e) => [`${e.file} ${e.name}`, e]));
+
+  let drift = 0;
+  for (const [key, entry] of currentByKey) {
+    const snapshot = lockedByKey.get(key);
+    if (snapshot === undefined) {
+      console.log(`+ ${entry.name} ${entry.version} (${entry.file}) not in snapshot`);
+      drift += 1;
+    } else if (snapshot.version !== entry.version) {
+      console.log(`~ ${entry.name} ${snapshot.version} -> ${entry.version} (${entry.file})`);
+      drift += 1;
+    }
+  }
+  for (const [key, entry] of lockedByKey) {
+    if (!currentByKey.has(key)) {
+      console.log(`- ${entry.name} ${entry.version} (${entry.file}) missing from tree`);
+      drift += 1;
+    }
+  }
+
+  if (drift > 0) {
+    console.log(`${drift} packages drift from ${lockFileName}`);
+    Deno.exit(1);
+  }
+  console.log(`All ${current.length} packages match ${lockFileName}`);
+}
@@ -0,0 +1,103 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { isStderrTerminal } from "../progress.ts";
+import type { Package } from "../types.ts";
+
+function daysSince(iso: string): number | null {
+  const elapsed = Date.now() - Date.parse(iso);
+  if (!Number.isFinite(elapsed) || elapsed < 0) return null;
+  return elapsed / 86_400_000;
+}
+
+function median(values: readonly number[]): number {
+  const sorted = [...values].sort((a, b) => a - b);
+  const mid = Math.floor(sorted.length / 2);
+  return sorted.length % 2 === 1
+    ? sorted[mid] ?? 0
+    : ((sorted[mid - 1] ?? 0) + (sorted[mid] ?? 0)) / 2;
+}
+
+/**
+ * `treeupdt stats [--jobs N]`: dependency hygiene numbers for the whole tree:
+ * totals per ecosystem, how stale the outdated packages are (days since their
+ * newest release shipped), pinned/ignored counts, and packages whose versions
+ * can't be resolved anywhere. The quarterly-review counterpart to `check`.
+ */
+export async function runStats(args: readonly string[]): Promise<void> {
+  let jobs: number | undefined;
+  for (let i = 0; i < args.length; i += 1) {
+    if (args[i] === "--jobs") {
+      jobs = Number(args[i + 1]);
+      if (!Number.isInteger(jobs) || jobs < 1) {
+        throw new Error(`Invalid --jobs value: ${args[i + 1]}`);
+      }
+      i += 1;
+    } else {
+      throw new Error("Usage: treeupdt stats [--jobs N]");
+    }
+  }
+
+  // Packages with no source hints produce no check entries at all, so the
+  // scanned set has to be collected separately from the pipeline's events.
+  const scanned: Package[] = [];
+  const report = await runCheckPipeline(".", {
+    ...(jobs !== undefined ? { jobs } : {}),
+    progress: isStderrTerminal(),
+    signal: interruptSignal(),
+    onEvent: (event) => {
+      if (event.kind === "package-found") scanned.push(event.package);
+    },
+  });
+
+  const perEcosystem = new Map<string, number>();
+  for (const pkg of scanned) {
+    perEcosystem.set(pkg.fileType, (perEcosystem.get(pkg.fileType) ?? 0) + 1);
+  }
+  const ecosystems = [...perEcosystem.entries()]
+    .sort((a, b) => b[1] - a[1] || a[0].localeCompare(b[0]))
+    .map(([fileType, count]) => `${fileType} ${count}`)
+    .join(", ");
+  console.log(`Packages: ${scanned.length} (${ecosystems || "none"})`);
+
+  const outdated = report.entries.filter((entry) => entry.updateAvailable === true);
+  const levels = { major: 0, minor: 0, patch: 0 };
+  for (const entry of outdated) {
+    if (entry.semverLevel !== undefined) levels[entry.semverLevel] += 1;
+  }
+  console.log(
+    `Updates available: ${outdated.length} ` +
+      `(${levels.major} major, ${levels.minor} minor, ${levels.patch} patch)`,
+  );
+
+  // Staleness: how long the newest eligible release has been waiting.
+  const staleness = outdated
+    .map((entry) =>
+      entry.latestPublishedAt !== undefined ? daysSince(entry.latestPublishedAt) : null
+    )
+    .filter((days): days is number => days !== null);
+  if (staleness.length > 0) {
+    const average = staleness.reduce((sum, days) => sum + days, 0) / staleness.length;
+    console.log(
+      `Staleness: avg ${Math.round(average)}d, median ${Math.round(median(staleness))}d ` +
+        `(over ${staleness.length} outdated packages with release dates)`,
+    );
+  }
+
+  const pinned = report.entries.filter((entry) => entry.pinVersion !== undefined).length;
+  const ignored = report.entries.filter((entry) => entry.ignored === true).length;
+  console.log(`Pinned: ${pinned}, ignored: ${ignored}`);
+
+  const hintless = scanned
+    .filter((pkg) => pkg.sourceHints.length === 0 && pkg.eolProduct === undefined).length;
+  const unknown = report.entries.filter((entry) => entry.unknownPackage === true).length;
+  console.log(
+    `No resolvable source: ${hintless + unknown} ` +
+      `(${hintless} without source hints, ${unknown} unknown to their source)`,
+  );
+
+  const errors = report.entries
+    .filter((entry) => entry.error !== undefined && entry.unknownPackage !== true).length;
+  if (errors > 0) {
+    console.log(`Check errors: ${errors}`);
+  }
+}
@@ -0,0 +1,128 @@
+import { interruptSignal } from "../cancel.ts";
+import { runCheckPipeline } from "../check.ts";
+import { loadConfig } from "../config.ts";
+import { loadPlugins } from "../plugins.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import type { Package, UpdateEntry } from "../types.ts";
+
+const treeFormats = ["text", "dot"] as const;
+
+type TreeFormat = (typeof treeFormats)[number];
+
+/** file -> section -> packages, with stable sorted keys for rendering. */
+function groupPackages(packages: readonly Package[]): Map<string, Map<string, Package[]>> {
+  const files = new Map<string, Map<string, Package[]>>();
+  for (const pkg of packages) {
+    const sections = files.get(pkg.file) ?? new Map<string, Package[]>();
+    files.set(pkg.file, sections);
+    const section = pkg.id.section === "" ? "(top level)" : pkg.id.section;
+    sections.set(section, [...(sections.get(section) ?? []), pkg]);
+  }
+  return files;
+}
+
+function statusSuffix(entry: UpdateEntry | undefined): string {
+  if (entry === undefined) return "";
+  if (entry.error !== undefined) return "  [error]";
+  if (entry.updateAvailable === true && entry.latest !== undefined) {
+    return `  -> ${entry.latest}`;
+  }
+  if (entry.blockedByStrategy === true) return "  [blocked by strategy]";
+  return "";
+}
+
+function renderTextTree(
+  files: ReadonlyMap<string, ReadonlyMap<string, readonly Package[]>>,
+  status: ReadonlyMap<string, UpdateEntry>,
+): string {
+  const lines: string[] = [];
+  for (const file of [...files.keys()].sort()) {
+    lines.push(file);
+    const sections = files.get(file) ?? new Map<string, readonly Package[]>();
+    const sectionNames = [...sections.keys()].sort();
+    for (const [s, section] of sectionNames.entries()) {
+      const lastSection = s === sectionNames.length - 1;
+      lines.push(`${lastSection ? "└──" : "├──"} ${section}`);
+      const packages = sections.get(section) ?? [];
+      for (const [p, pkg] of packages.entries()) {
+        const connector = p === packages.length - 1 ? "└──" : "├──";
+        const suffix = statusSuffix(status.get(`${pkg.file} ${pkg.name}`));
+        lines.push(
+          `${lastSection ? "   " : "│  "} ${connector} ${pkg.id.name} ${pkg.version}${suffix}`,
+        );
+      }
+    }
+  }
+  return lines.join("\n");
+}
+
+function dotLabel(text: string): string {
+  return `"${text.replaceAll("\\", "\\\\").replaceAll('"', '\\"')}"`;
+}
+
+function renderDotTree(
+  files: ReadonlyMap<string, ReadonlyMap<string, readonly Package[]>>,
+  status: ReadonlyMap<string, UpdateEntry>,
+): string {
+  const lines = ["digraph treeupdt {", "  rankdir=LR;", "  node [shape=box];"];
+  for (const file of [...files.keys()].sort()) {
+    const sections = files.get(file) ?? new Map<string, readonly Package[]>();
+    for (const section of [...sections.keys()].sort()) {
+      const sectionId = `${file}#${section}`;
+      lines.push(`  ${dotLabel(sectionId)} [label=${dotLabel(section)}];`);
+      lines.push(`  ${dotLabel(file)} -> ${dotLabel(sectionId)};`);
+      for (const pkg of sections.get(section) ?? []) {
+        const pkgId = `${sectionId}#${pkg.id.name}`;
+        const suffix = statusSuffix(status.get(`${pkg.file} ${pkg.name}`)).trimStart();
+        const label = `${pkg.id.name} ${pkg.version}${suffix === "" ? "" : `\n${suffix}`}`;
+        lines.push(`  ${dotLabel(pkgId)} [label=${dotLabel(label)}];`);
+        lines.push(`  ${dotLabel(sectionId)} -> ${dotLabel(pkgId)};`);
+      }
+    }
+  }
+  lines.push("}");
+  return lines.join("\n");
+}
+
+/**
+ * `treeupdt tree [--format text|dot] [--check]`: one-screen hierarchy of
+ * files -> sections -> packages under management; `--check` annotates each
+ * package with its update status and `dot` feeds graphviz.
+ */
+export async function runTree(args: readonly string[]): Promise<void> {
+  let format: TreeFormat = "text";
+  let check = false;
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === "--format") {
+      const value = args[i + 1];
+      if (value === undefined || !(treeFormats as readonly string[]).includes(value)) {
+        throw new Error(`Invalid --format value: ${value ?? "<missing>"} (expected text|dot)`);
+      }
+      format = value as TreeFormat;
+      i += 1;
+    } else if (arg === "--check") {
+      check = true;
+    } else {
+      throw new Error(`Unknown tree argument: ${arg}`);
+    }
+  }
+
+  const config = await loadConfig(".");
+  const registry = defaultScannerRegistry();
+  for (const scanner of (await loadPlugins(config)).scanners) {
+    registry.register(scanner);
+  }
+  const packages = await scanTree(".", registry, config.global.excludePaths ?? []);
+
+  const status = new Map<string, UpdateEntry>();
+  if (check) {
+    const report = await runCheckPipeline(".", { config, signal: interruptSignal() });
+    for (const entry of report.entries) {
+      status.set(`${entry.file} ${entry.name}`, entry);
+    }
+  }
+
+  const files = groupPackages(packages);
+  console.log(format === "dot" ? renderDotTree(files, status) : renderTextTree(files, status));
+}
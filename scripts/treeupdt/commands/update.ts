@@ -0,0 +1,133 @@
+import { runCaptureChecked, runChecked } from "../../updater/command.ts";
+import { interruptSignal } from "../cancel.ts";
+import { defaultCommitTemplate, renderCommitMessage } from "../commitTemplate.ts";
+import {
+  effectiveMinimumReleaseAge,
+  effectivePinVersion,
+  loadConfig,
+  matchGroup,
+} from "../config.ts";
+import { appendHistory, newTransactionId } from "../history.ts";
+import { withLock } from "../lock.ts";
+import { defaultScannerRegistry, scanTree } from "../scan.ts";
+import { defaultUpdaterRegistry } from "../updaters.ts";
+
+function stripFlag(args: readonly string[], flag: string): { args: string[]; present: boolean } {
+  const remaining = args.filter((a) => a !== flag);
+  return { args: remaining, present: remaining.length !== args.length };
+}
+
+/** Rewrite every pinned package whose manifest drifted back to its pin. */
+async function enforcePins(sync: boolean): Promise<void> {
+  const signal = interruptSignal();
+  const transactionId = newTransactionId();
+  await withLock(".", async () => {
+    const config = await loadConfig(".");
+    const packages = await scanTree(
+      ".",
+      defaultScannerRegistry(),
+      config.global.excludePaths ?? [],
+    );
+    const updaters = defaultUpdaterRegistry();
+
+    let enforced = 0;
+    for (const pkg of packages) {
+      if (signal.aborted) {
+        console.log("Interrupted; remaining pins left unenforced");
+        break;
+      }
+      const pin = effectivePinVersion(config, pkg.name);
+      if (pin === undefined || pkg.version === pin) continue;
+
+      const updater = updaters.forFile(pkg.file);
+      if (!updater) {
+        console.log(`Cannot enforce pin for ${pkg.name}: unsupported file ${pkg.file}`);
+        continue;
+      }
+      // No minimum-release-age here: an explicit pin is its own approval,
+      // and it may legitimately be a downgrade.
+      const outcome = await updater.apply(
+        pkg.file,
+        pkg.sourceHints[0]?.identifier ?? pkg.name,
+        pin,
+        { sync, signal },
+      );
+      console.log(`Enforced pin: ${pkg.name} ${outcome.oldVersion} -> ${pin} in ${pkg.file}`);
+      await appendHistory({
+        timestamp: new Date().toISOString(),
+        file: pkg.file,
+        name: pkg.name,
+        oldVersion: outcome.oldVersion,
+        newVersion: pin,
+        transactionId,
+      });
+      enforced += 1;
+    }
+    if (enforced === 0) {
+      console.log("All pins already satisfied");
+    }
+  });
+}
+
+export async function runUpdate(rawArgs: readonly string[]): Promise<void> {
+  const noSync = stripFlag(rawArgs, "--no-sync");
+  const commit = stripFlag(noSync.args, "--commit");
+  const enforce = stripFlag(commit.args, "--enforce-pins");
+  if (enforce.present) {
+    if (enforce.args.length > 0) {
+      throw new Error("update --enforce-pins takes no further arguments");
+    }
+    await enforcePins(!noSync.present);
+    return;
+  }
+  const [file, packageName, newVersion] = enforce.args;
+  if (!file || !packageName || !newVersion) {
+    throw new Error("Usage: treeupdt update <file> <package> <version> [--no-sync] [--commit]");
+  }
+
+  const signal = interruptSignal();
+  await withLock(".", async () => {
+    const config = await loadConfig(".");
+    const minimumReleaseAge = effectiveMinimumReleaseAge(config, packageName);
+
+    const updater = defaultUpdaterRegistry().forFile(file);
+    if (!updater) {
+      throw new Error(`Unsupported file: ${file}`);
+    }
+
+    const outcome = await updater.apply(file, packageName, newVersion, {
+      sync: !noSync.present,
+      ...(minimumReleaseAge !== null ? { minimumReleaseAge } : {}),
+      signal,
+    });
+
+    console.log(`Updated ${packageName} from ${outcome.oldVersion} to ${newVersion} in ${file}`);
+
+    let commitSha: string | undefined;
+    if (commit.present) {
+      const group = matchGroup(config, packageName, file, []);
+      const template = (group !== undefined ? config.groups[group]?.commitTemplate : undefined) ??
+        config.global.commitTemplate ?? defaultCommitTemplate;
+      const message = renderCommitMessage(template, {
+        name: packageName,
+        old: outcome.oldVersion,
+        new: newVersion,
+        file,
+      });
+      await runChecked("git", ["add", "--", file]);
+      await runChecked("git", ["commit", "-m", message]);
+      commitSha = (await runCaptureChecked("git", ["rev-parse", "HEAD"])).stdout.trim();
+      console.log(`Committed: ${message}`);
+    }
+
+    await appendHistory({
+      timestamp: new Date().toISOString(),
+      file,
+      name: packageName,
+      oldVersion: outcome.oldVersion,
+      newVersion,
+      transactionId: newTransactionId(),
+      ...(commitSha !== undefined ? { commitSha } : {}),
+    });
+  });
+}
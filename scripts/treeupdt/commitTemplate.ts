@@ -0,0 +1,35 @@
+export const defaultCommitTemplate = "chore(deps): bump {{name}} from {{old}} to {{new}}";
+
+export type CommitTemplateVars = Readonly<{
+  name: string;
+  old: string;
+  new: string;
+  file?: string;
+  source?: string;
+  level?: string;
+}>;
+
+/**
+ * Render a commit message template like
+ * `chore(deps): bump {{name}} from {{old}} to {{new}}`.
+ * Unknown placeholders are an error so typos in config don't produce
+ * half-rendered commit messages.
+ */
+export function renderCommitMessage(template: string, vars: CommitTemplateVars): string {
+  const available: Record<string, string> = {
+    name: vars.name,
+    old: vars.old,
+    new: vars.new,
+    file: vars.file ?? "",
+    source: vars.source ?? "",
+    level: vars.level ?? "",
+  };
+
+  return template.replaceAll(/\{\{([A-Za-z0-9_]+)\}\}/g, (_match, key: string) => {
+    const value = available[key];
+    if (value === undefined) {
+      throw new Error(`Unknown commit template variable: {{${key}}}`);
+    }
+    return value;
+  });
+}
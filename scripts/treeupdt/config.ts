@@ -0,0 +1,888 @@
+import { dirname, join } from "node:path";
+
+import { assertRecord, isRecord } from "../updater/assert.ts";
+import { fileExists } from "../updater/fs.ts";
+import { type CacheBackend, cacheBackends, isCacheBackend } from "./cache.ts";
+import { emptyFilter, type Filter } from "./filter.ts";
+import { matchGlob } from "./glob.ts";
+import { warn } from "./log.ts";
+import { parseDuration } from "./releaseAge.ts";
+import { setCacheDirOverride } from "./state.ts";
+import { isStrategy, strategies } from "./strategy.ts";
+import type { Strategy } from "./types.ts";
+
+export const configFileName = ".treeupdt.json";
+
+export const webhookFormats = ["json", "slack", "discord", "matrix"] as const;
+
+export type WebhookFormat = (typeof webhookFormats)[number];
+
+/** A notification target for `check --notify`; see notify.ts. */
+export type WebhookConfig = Readonly<{
+  /** Endpoint URL; use `${VAR}` interpolation to keep secrets out of the file. */
+  url: string;
+  /** Payload shape. Defaults to `json`. */
+  format: WebhookFormat;
+}>;
+
+export type GlobalConfig = Readonly<{
+  commitTemplate?: string;
+  /** Cooldown like `7d`: versions younger than this are not reported or applied. */
+  minimumReleaseAge?: string;
+  strategy?: Strategy;
+  /** Preferred source order when a package has several hints. */
+  sourcePriority?: readonly string[];
+  /** Globs (relative to the root) excluded from scanning. */
+  excludePaths?: readonly string[];
+  filters?: Filter;
+  /** Default strategy per file type, between the global default and package overrides. */
+  strategyByType?: Readonly<Record<string, Strategy>>;
+  /** Response cache backend: `files` (one file per key) or `indexed`. */
+  cacheBackend?: CacheBackend;
+  /** Default cache TTL like `1h`; per-source `cache-ttl` overrides it. */
+  cacheTtl?: string;
+  /** Cache location override; `TREEUPDT_CACHE_DIR` wins over the config. */
+  cacheDir?: string;
+  /** When set, only packages matching one of these name globs are handled. */
+  onlyPackages?: readonly string[];
+  /** Packages matching one of these name globs are skipped repo-wide. */
+  denyPackages?: readonly string[];
+  /** Targets `check --notify` posts summaries to. */
+  webhooks?: readonly WebhookConfig[];
+}>;
+
+export type PackageConfig = Readonly<{
+  minimumReleaseAge?: string;
+  /** Hold the package at exactly this version. */
+  pinVersion?: string;
+  strategy?: Strategy;
+  /** Sources to try in order, falling through on error or no releases. */
+  preferredSource?: readonly string[];
+  /** Cadence window (`monthly`, cron, ...); see schedule.ts. */
+  schedule?: string;
+  /** Regex whose first capture group extracts the version from a tag name. */
+  extractVersion?: string;
+  /** Template rendering a version back into a tag, e.g. `release/{{version}}`. */
+  tagTemplate?: string;
+}>;
+
+export type SourceConfig = Readonly<{
+  /** Literal token; prefer `token-env` so secrets stay out of the file. */
+  token?: string;
+  /** Environment variable to read the token from. */
+  tokenEnv?: string;
+  /** Registry/API root, e.g. a GitHub Enterprise or npm mirror URL. */
+  baseUrl?: string;
+  /** How long responses may be served from cache, e.g. `1h`. */
+  cacheTtl?: string;
+  /** Per-source concurrency cap. */
+  concurrency?: number;
+  enabled?: boolean;
+}>;
+
+/**
+ * A named set of packages sharing behavior. Matchers within a group are
+ * alternatives: a package belongs to the group when any of them matches.
+ */
+export type GroupConfig = Readonly<{
+  /** Name globs selecting members. */
+  packages?: readonly string[];
+  /** File globs selecting members. */
+  files?: readonly string[];
+  /** Source types selecting members. */
+  sources?: readonly string[];
+  strategy?: Strategy;
+  /** Commit message template for updates applied as part of this group. */
+  commitTemplate?: string;
+  /** Cadence window shared by the group's members. */
+  schedule?: string;
+}>;
+
+/** A WASM plugin module extending treeupdt; see plugins.ts for the contract. */
+export type PluginConfig = Readonly<{
+  /** Path to the compiled WASM module. */
+  path: string;
+  kind: "scanner" | "source";
+  /** Scanner plugins: the file type label for the packages they produce. */
+  fileType?: string;
+  /** Scanner plugins: manifest globs the plugin handles. */
+  files?: readonly string[];
+  /** Source plugins: the source type they serve. */
+  sourceType?: string;
+}>;
+
+export type Config = Readonly<{
+  global: GlobalConfig;
+  packages: Readonly<Record<string, PackageConfig>>;
+  sources: Readonly<Record<string, SourceConfig>>;
+  groups: Readonly<Record<string, GroupConfig>>;
+  plugins: Readonly<Record<string, PluginConfig>>;
+  /** Named overlays selected with `--profile`; empty inside a profile itself. */
+  profiles: Readonly<Record<string, Config>>;
+}>;
+
+export const defaultConfig: Config = {
+  global: {},
+  packages: {},
+  sources: {},
+  groups: {},
+  plugins: {},
+  profiles: {},
+};
+
+/**
+ * Expand `${VAR}` placeholders from the environment so secrets and
+ * machine-specific paths don't have to be written into the config file.
+ * Missing variables are a hard error rather than an empty string.
+ */
+function interpolate(value: string, context: string): string {
+  return value.replaceAll(/\$\{([A-Za-z_][A-Za-z0-9_]*)\}/g, (_, name: string) => {
+    const resolved = Deno.env.get(name);
+    if (resolved === undefined) {
+      throw new Error(`${context}: environment variable ${name} is not set`);
+    }
+    return resolved;
+  });
+}
+
+function optString(
+  rec: Readonly<Record<string, unknown>>,
+  key: string,
+  context: string,
+): string | undefined {
+  const value = rec[key];
+  if (value === undefined) return undefined;
+  if (typeof value !== "string") {
+    throw new Error(`${context}.${key}: expected string`);
+  }
+  return interpolate(value, `${context}.${key}`);
+}
+
+function optStringArray(
+  rec: Readonly<Record<string, unknown>>,
+  key: string,
+  context: string,
+): readonly string[] | undefined {
+  const value = rec[key];
+  if (value === undefined) return undefined;
+  if (!Array.isArray(value) || !value.every((item) => typeof item === "string")) {
+    throw new Error(`${context}.${key}: expected array of strings`);
+  }
+  return value.map((item: string) => interpolate(item, `${context}.${key}`));
+}
+
+function optStrategy(
+  rec: Readonly<Record<string, unknown>>,
+  context: string,
+): Strategy | undefined {
+  const value = optString(rec, "strategy", context);
+  if (value === undefined) return undefined;
+  if (!isStrategy(value)) {
+    throw new Error(`${context}.strategy: expected one of ${strategies.join(", ")}`);
+  }
+  return value;
+}
+
+function optNumber(
+  rec: Readonly<Record<string, unknown>>,
+  key: string,
+  context: string,
+): number | undefined {
+  const value = rec[key];
+  if (value === undefined) return undefined;
+  if (typeof value !== "number" || !Number.isInteger(value) || value < 1) {
+    throw new Error(`${context}.${key}: expected positive integer`);
+  }
+  return value;
+}
+
+function optBoolean(
+  rec: Readonly<Record<string, unknown>>,
+  key: string,
+  context: string,
+): boolean | undefined {
+  const value = rec[key];
+  if (value === undefined) return undefined;
+  if (typeof value !== "boolean") {
+    throw new Error(`${context}.${key}: expected boolean`);
+  }
+  return value;
+}
+
+function parseFilters(data: unknown, context: string): Filter | undefined {
+  if (data === undefined) return undefined;
+  assertRecord(data, `${context}: expected object`);
+  return {
+    fileTypes: optStringArray(data, "file-types", context) ?? emptyFilter.fileTypes,
+    sources: optStringArray(data, "sources", context) ?? emptyFilter.sources,
+    namePatterns: optStringArray(data, "name-patterns", context) ?? emptyFilter.namePatterns,
+  };
+}
+
+function parseStrategyByType(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, Strategy>> | undefined {
+  if (data === undefined) return undefined;
+  assertRecord(data, `${context}: expected object`);
+  const byType: Record<string, Strategy> = {};
+  for (const [fileType, value] of Object.entries(data)) {
+    if (typeof value !== "string" || !isStrategy(value)) {
+      throw new Error(`${context}.${fileType}: expected one of ${strategies.join(", ")}`);
+    }
+    byType[fileType] = value;
+  }
+  return byType;
+}
+
+function parseWebhooks(data: unknown, context: string): readonly WebhookConfig[] | undefined {
+  if (data === undefined) return undefined;
+  if (!Array.isArray(data)) {
+    throw new Error(`${context}: expected array`);
+  }
+  return data.map((item, i) => {
+    assertRecord(item, `${context}[${i}]: expected object`);
+    const url = optString(item, "url", `${context}[${i}]`);
+    if (url === undefined) {
+      throw new Error(`${context}[${i}].url: required`);
+    }
+    const format = optString(item, "format", `${context}[${i}]`) ?? "json";
+    if (!(webhookFormats as readonly string[]).includes(format)) {
+      throw new Error(`${context}[${i}].format: expected one of ${webhookFormats.join(", ")}`);
+    }
+    return { url, format: format as WebhookFormat };
+  });
+}
+
+function parseGlobalConfig(data: unknown, context: string): GlobalConfig {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const commitTemplate = optString(data, "commit-template", context);
+  const minimumReleaseAge = optString(data, "minimum-release-age", context);
+  const strategy = optStrategy(data, context);
+  const sourcePriority = optStringArray(data, "source-priority", context);
+  const excludePaths = optStringArray(data, "exclude-paths", context);
+  const filters = parseFilters(data["filters"], `${context}.filters`);
+  const strategyByType = parseStrategyByType(
+    data["strategy-by-type"],
+    `${context}.strategy-by-type`,
+  );
+  const cacheBackend = optString(data, "cache-backend", context);
+  if (cacheBackend !== undefined && !isCacheBackend(cacheBackend)) {
+    throw new Error(`${context}.cache-backend: expected one of ${cacheBackends.join(", ")}`);
+  }
+  const globalCacheTtl = optString(data, "cache-ttl", context);
+  const cacheDir = optString(data, "cache-dir", context);
+  const onlyPackages = optStringArray(data, "only-packages", context);
+  const denyPackages = optStringArray(data, "deny-packages", context);
+  const webhooks = parseWebhooks(data["webhooks"], `${context}.webhooks`);
+  return {
+    ...(commitTemplate !== undefined ? { commitTemplate } : {}),
+    ...(minimumReleaseAge !== undefined ? { minimumReleaseAge } : {}),
+    ...(strategy !== undefined ? { strategy } : {}),
+    ...(sourcePriority !== undefined ? { sourcePriority } : {}),
+    ...(excludePaths !== undefined ? { excludePaths } : {}),
+    ...(filters !== undefined ? { filters } : {}),
+    ...(strategyByType !== undefined ? { strategyByType } : {}),
+    ...(cacheBackend !== undefined ? { cacheBackend } : {}),
+    ...(globalCacheTtl !== undefined ? { cacheTtl: globalCacheTtl } : {}),
+    ...(cacheDir !== undefined ? { cacheDir } : {}),
+    ...(onlyPackages !== undefined ? { onlyPackages } : {}),
+    ...(denyPackages !== undefined ? { denyPackages } : {}),
+    ...(webhooks !== undefined ? { webhooks } : {}),
+  };
+}
+
+function parsePackageConfig(data: unknown, context: string): PackageConfig {
+  assertRecord(data, `${context}: expected object`);
+  const minimumReleaseAge = optString(data, "minimum-release-age", context);
+  const pinVersion = optString(data, "pin-version", context);
+  const strategy = optStrategy(data, context);
+  const preferredSource = optStringArray(data, "preferred-source", context);
+  const schedule = optString(data, "schedule", context);
+  const extractVersion = optString(data, "extract-version", context);
+  const tagTemplate = optString(data, "tag-template", context);
+  return {
+    ...(minimumReleaseAge !== undefined ? { minimumReleaseAge } : {}),
+    ...(pinVersion !== undefined ? { pinVersion } : {}),
+    ...(strategy !== undefined ? { strategy } : {}),
+    ...(preferredSource !== undefined ? { preferredSource } : {}),
+    ...(schedule !== undefined ? { schedule } : {}),
+    ...(extractVersion !== undefined ? { extractVersion } : {}),
+    ...(tagTemplate !== undefined ? { tagTemplate } : {}),
+  };
+}
+
+function parsePackagesConfig(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, PackageConfig>> {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const packages: Record<string, PackageConfig> = {};
+  for (const [name, value] of Object.entries(data)) {
+    packages[name] = parsePackageConfig(value, `${context}.${name}`);
+  }
+  return packages;
+}
+
+function parseSourceConfig(data: unknown, context: string): SourceConfig {
+  assertRecord(data, `${context}: expected object`);
+  const token = optString(data, "token", context);
+  const tokenEnv = optString(data, "token-env", context);
+  const baseUrl = optString(data, "base-url", context);
+  const cacheTtl = optString(data, "cache-ttl", context);
+  const concurrency = optNumber(data, "concurrency", context);
+  const enabled = optBoolean(data, "enabled", context);
+  return {
+    ...(token !== undefined ? { token } : {}),
+    ...(tokenEnv !== undefined ? { tokenEnv } : {}),
+    ...(baseUrl !== undefined ? { baseUrl } : {}),
+    ...(cacheTtl !== undefined ? { cacheTtl } : {}),
+    ...(concurrency !== undefined ? { concurrency } : {}),
+    ...(enabled !== undefined ? { enabled } : {}),
+  };
+}
+
+function parseSourcesConfig(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, SourceConfig>> {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const sources: Record<string, SourceConfig> = {};
+  for (const [type, value] of Object.entries(data)) {
+    sources[type] = parseSourceConfig(value, `${context}.${type}`);
+  }
+  return sources;
+}
+
+function parseGroupConfig(data: unknown, context: string): GroupConfig {
+  assertRecord(data, `${context}: expected object`);
+  const packages = optStringArray(data, "packages", context);
+  const files = optStringArray(data, "files", context);
+  const sources = optStringArray(data, "sources", context);
+  const strategy = optStrategy(data, context);
+  const commitTemplate = optString(data, "commit-template", context);
+  const schedule = optString(data, "schedule", context);
+  return {
+    ...(packages !== undefined ? { packages } : {}),
+    ...(files !== undefined ? { files } : {}),
+    ...(sources !== undefined ? { sources } : {}),
+    ...(strategy !== undefined ? { strategy } : {}),
+    ...(commitTemplate !== undefined ? { commitTemplate } : {}),
+    ...(schedule !== undefined ? { schedule } : {}),
+  };
+}
+
+function parseGroupsConfig(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, GroupConfig>> {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const groups: Record<string, GroupConfig> = {};
+  for (const [name, value] of Object.entries(data)) {
+    groups[name] = parseGroupConfig(value, `${context}.${name}`);
+  }
+  return groups;
+}
+
+function parsePluginConfig(data: unknown, context: string): PluginConfig {
+  assertRecord(data, `${context}: expected object`);
+  const path = optString(data, "path", context);
+  if (path === undefined) {
+    throw new Error(`${context}.path: required`);
+  }
+  const kind = optString(data, "kind", context);
+  if (kind !== "scanner" && kind !== "source") {
+    throw new Error(`${context}.kind: expected scanner or source`);
+  }
+  const fileType = optString(data, "file-type", context);
+  const files = optStringArray(data, "files", context);
+  const sourceType = optString(data, "source-type", context);
+  return {
+    path,
+    kind,
+    ...(fileType !== undefined ? { fileType } : {}),
+    ...(files !== undefined ? { files } : {}),
+    ...(sourceType !== undefined ? { sourceType } : {}),
+  };
+}
+
+function parsePluginsConfig(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, PluginConfig>> {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const plugins: Record<string, PluginConfig> = {};
+  for (const [name, value] of Object.entries(data)) {
+    plugins[name] = parsePluginConfig(value, `${context}.${name}`);
+  }
+  return plugins;
+}
+
+function parseProfilesConfig(
+  data: unknown,
+  context: string,
+): Readonly<Record<string, Config>> {
+  if (data === undefined) return {};
+  assertRecord(data, `${context}: expected object`);
+  const profiles: Record<string, Config> = {};
+  for (const [name, value] of Object.entries(data)) {
+    assertRecord(value, `${context}.${name}: expected object`);
+    profiles[name] = {
+      global: parseGlobalConfig(value["global"], `${context}.${name}.global`),
+      packages: parsePackagesConfig(value["packages"], `${context}.${name}.packages`),
+      sources: parseSourcesConfig(value["sources"], `${context}.${name}.sources`),
+      groups: parseGroupsConfig(value["groups"], `${context}.${name}.groups`),
+      plugins: parsePluginsConfig(value["plugins"], `${context}.${name}.plugins`),
+      profiles: {},
+    };
+  }
+  return profiles;
+}
+
+/**
+ * Schema version written by `config migrate`. Bumped when keys are renamed
+ * or reshaped; files without the field are treated as version 1.
+ */
+export const currentConfigVersion = 2;
+
+export function configVersion(data: unknown): number {
+  if (!isRecord(data)) return currentConfigVersion;
+  const version = data["config-version"];
+  return typeof version === "number" && Number.isInteger(version) ? version : 1;
+}
+
+/**
+ * Rewrite deprecated keys to their current equivalents, in place. Returns a
+ * human-readable list of the changes made; empty means already current.
+ */
+export function migrateConfigData(data: Record<string, unknown>): string[] {
+  const changes: string[] = [];
+
+  const renameStrategy = (table: Record<string, unknown>, context: string): void => {
+    if (table["update-strategy"] !== undefined && table["strategy"] === undefined) {
+      table["strategy"] = table["update-strategy"];
+      delete table["update-strategy"];
+      changes.push(`${context}: renamed update-strategy to strategy`);
+    }
+  };
+
+  const global = data["global"];
+  if (isRecord(global)) renameStrategy(global, "global");
+  const packages = data["packages"];
+  if (isRecord(packages)) {
+    for (const [name, pkg] of Object.entries(packages)) {
+      if (!isRecord(pkg)) continue;
+      renameStrategy(pkg, `packages.${name}`);
+      // preferred-source grew from a single value into a fallback chain.
+      if (typeof pkg["preferred-source"] === "string") {
+        pkg["preferred-source"] = [pkg["preferred-source"]];
+        changes.push(`packages.${name}: wrapped preferred-source into a list`);
+      }
+    }
+  }
+
+  if (configVersion(data) < currentConfigVersion) {
+    data["config-version"] = currentConfigVersion;
+    changes.push(`config-version: set to ${currentConfigVersion}`);
+  }
+  return changes;
+}
+
+export function parseConfig(data: unknown, context: string): Config {
+  assertRecord(data, `${context}: expected object`);
+  return {
+    global: parseGlobalConfig(data["global"], `${context}.global`),
+    packages: parsePackagesConfig(data["packages"], `${context}.packages`),
+    sources: parseSourcesConfig(data["sources"], `${context}.sources`),
+    groups: parseGroupsConfig(data["groups"], `${context}.groups`),
+    plugins: parsePluginsConfig(data["plugins"], `${context}.plugins`),
+    profiles: parseProfilesConfig(data["profiles"], `${context}.profiles`),
+  };
+}
+
+/** Overlay a named profile onto the config; unknown names are an error. */
+export function applyProfile(config: Config, name: string): Config {
+  const profile = config.profiles[name];
+  if (profile === undefined) {
+    const known = Object.keys(config.profiles);
+    throw new Error(
+      `Unknown profile: ${name}${known.length > 0 ? ` (known: ${known.join(", ")})` : ""}`,
+    );
+  }
+  return mergeConfig(config, profile);
+}
+
+/** The token configured for a source, from `token` or `token-env`. */
+export function resolveSourceToken(config: SourceConfig): string | undefined {
+  if (config.token !== undefined) return config.token;
+  if (config.tokenEnv !== undefined) {
+    const value = Deno.env.get(config.tokenEnv);
+    if (value !== undefined && value !== "") return value;
+  }
+  return undefined;
+}
+
+const knownTopLevelKeys = [
+  "config-version",
+  "global",
+  "packages",
+  "sources",
+  "groups",
+  "plugins",
+  "profiles",
+] as const;
+const knownGlobalKeys = [
+  "commit-template",
+  "minimum-release-age",
+  "strategy",
+  "source-priority",
+  "exclude-paths",
+  "filters",
+  "strategy-by-type",
+  "cache-backend",
+  "cache-ttl",
+  "cache-dir",
+  "only-packages",
+  "deny-packages",
+  "webhooks",
+] as const;
+const knownFilterKeys = ["file-types", "sources", "name-patterns"] as const;
+const knownPackageKeys = [
+  "minimum-release-age",
+  "pin-version",
+  "strategy",
+  "preferred-source",
+  "schedule",
+  "extract-version",
+  "tag-template",
+] as const;
+const knownGroupKeys = [
+  "packages",
+  "files",
+  "sources",
+  "strategy",
+  "commit-template",
+  "schedule",
+] as const;
+const knownSourceKeys = [
+  "token",
+  "token-env",
+  "base-url",
+  "cache-ttl",
+  "concurrency",
+  "enabled",
+] as const;
+const knownPluginKeys = [
+  "path",
+  "kind",
+  "file-type",
+  "files",
+  "source-type",
+] as const;
+
+function unknownKeys(
+  data: Readonly<Record<string, unknown>>,
+  known: readonly string[],
+  context: string,
+  issues: string[],
+): void {
+  for (const key of Object.keys(data)) {
+    if (!known.includes(key)) {
+      issues.push(`${context}.${key}: unknown key (known: ${known.join(", ")})`);
+    }
+  }
+}
+
+/**
+ * Collect problems `parseConfig` would silently tolerate or only surface one
+ * at a time: unknown keys, strategy typos, and sections of the wrong shape.
+ * Returns human-readable findings; an empty list means the config is clean.
+ */
+export function lintConfig(data: unknown, context: string): string[] {
+  const issues: string[] = [];
+  if (!isRecord(data)) {
+    return [`${context}: expected a JSON object`];
+  }
+  unknownKeys(data, knownTopLevelKeys, context, issues);
+
+  const global = data["global"];
+  if (global !== undefined && isRecord(global)) {
+    unknownKeys(global, knownGlobalKeys, `${context}.global`, issues);
+    const filters = global["filters"];
+    if (filters !== undefined && isRecord(filters)) {
+      unknownKeys(filters, knownFilterKeys, `${context}.global.filters`, issues);
+    }
+    const strategy = global["strategy"];
+    if (typeof strategy === "string" && !isStrategy(strategy)) {
+      issues.push(
+        `${context}.global.strategy: ${strategy} is not one of ${strategies.join(", ")}`,
+      );
+    }
+    const byType = global["strategy-by-type"];
+    if (byType !== undefined && isRecord(byType)) {
+      for (const [fileType, value] of Object.entries(byType)) {
+        if (typeof value === "string" && !isStrategy(value)) {
+          issues.push(
+            `${context}.global.strategy-by-type.${fileType}: ${value} is not one of ` +
+              strategies.join(", "),
+          );
+        }
+      }
+    }
+  }
+
+  const packages = data["packages"];
+  if (packages !== undefined && isRecord(packages)) {
+    for (const [name, pkg] of Object.entries(packages)) {
+      if (!isRecord(pkg)) continue;
+      unknownKeys(pkg, knownPackageKeys, `${context}.packages.${name}`, issues);
+      const strategy = pkg["strategy"];
+      if (typeof strategy === "string" && !isStrategy(strategy)) {
+        issues.push(
+          `${context}.packages.${name}.strategy: ${strategy} ` +
+            `is not one of ${strategies.join(", ")}`,
+        );
+      }
+    }
+  }
+
+  const sources = data["sources"];
+  if (sources !== undefined && isRecord(sources)) {
+    for (const [type, source] of Object.entries(sources)) {
+      if (!isRecord(source)) continue;
+      unknownKeys(source, knownSourceKeys, `${context}.sources.${type}`, issues);
+    }
+  }
+
+  const groups = data["groups"];
+  if (groups !== undefined && isRecord(groups)) {
+    for (const [name, group] of Object.entries(groups)) {
+      if (!isRecord(group)) continue;
+      unknownKeys(group, knownGroupKeys, `${context}.groups.${name}`, issues);
+      const strategy = group["strategy"];
+      if (typeof strategy === "string" && !isStrategy(strategy)) {
+        issues.push(
+          `${context}.groups.${name}.strategy: ${strategy} is not one of ${strategies.join(", ")}`,
+        );
+      }
+    }
+  }
+
+  const plugins = data["plugins"];
+  if (plugins !== undefined && isRecord(plugins)) {
+    for (const [name, plugin] of Object.entries(plugins)) {
+      if (!isRecord(plugin)) continue;
+      unknownKeys(plugin, knownPluginKeys, `${context}.plugins.${name}`, issues);
+      const kind = plugin["kind"];
+      if (typeof kind === "string" && kind !== "scanner" && kind !== "source") {
+        issues.push(`${context}.plugins.${name}.kind: ${kind} is not scanner or source`);
+      }
+    }
+  }
+  return issues;
+}
+
+/** Per-package cooldown in milliseconds, falling back to the global setting. */
+export function effectiveMinimumReleaseAge(config: Config, packageName: string): string | null {
+  return config.packages[packageName]?.minimumReleaseAge ??
+    config.global.minimumReleaseAge ?? null;
+}
+
+/** Version a package is pinned to, if any. */
+export function effectivePinVersion(config: Config, packageName: string): string | undefined {
+  return config.packages[packageName]?.pinVersion;
+}
+
+/** Cadence window for a package, falling back to its group's schedule. */
+export function effectiveSchedule(
+  config: Config,
+  packageName: string,
+  group?: string,
+): string | undefined {
+  return config.packages[packageName]?.schedule ??
+    (group !== undefined ? config.groups[group]?.schedule : undefined);
+}
+
+/**
+ * Cache TTL for a source in milliseconds, from the source's `cache-ttl`,
+ * the global default, then the fallback the caller supplies.
+ */
+export function effectiveCacheTtlMs(
+  config: Config,
+  sourceType: string,
+  fallbackMs: number,
+): number {
+  const raw = config.sources[sourceType]?.cacheTtl ?? config.global.cacheTtl;
+  return raw !== undefined ? parseDuration(raw) : fallbackMs;
+}
+
+/** Source fallback chain for a package, if one is configured. */
+export function effectivePreferredSources(
+  config: Config,
+  packageName: string,
+): readonly string[] | undefined {
+  return config.packages[packageName]?.preferredSource;
+}
+
+/** First group whose matchers cover the package, in declaration order. */
+export function matchGroup(
+  config: Config,
+  packageName: string,
+  file: string,
+  sourceTypes: readonly string[],
+): string | undefined {
+  for (const [name, group] of Object.entries(config.groups)) {
+    if (
+      (group.packages?.some((pattern) => matchGlob(pattern, packageName)) ?? false) ||
+      (group.files?.some((pattern) => matchGlob(pattern, file)) ?? false) ||
+      (group.sources?.some((type) => sourceTypes.includes(type)) ?? false)
+    ) {
+      return name;
+    }
+  }
+  return undefined;
+}
+
+/**
+ * Per-package strategy, falling back to the package's group, the file type's
+ * default, the global setting, then `latest`.
+ */
+export function effectiveStrategy(
+  config: Config,
+  packageName: string,
+  fileType?: string,
+  group?: string,
+): Strategy {
+  return config.packages[packageName]?.strategy ??
+    (group !== undefined ? config.groups[group]?.strategy : undefined) ??
+    (fileType !== undefined ? config.global.strategyByType?.[fileType] : undefined) ??
+    config.global.strategy ?? "latest";
+}
+
+/** Overlay wins key-by-key; package tables merge per package. */
+export function mergeConfig(base: Config, overlay: Config): Config {
+  const packages: Record<string, PackageConfig> = { ...base.packages };
+  for (const [name, pkg] of Object.entries(overlay.packages)) {
+    packages[name] = { ...packages[name], ...pkg };
+  }
+  const sources: Record<string, SourceConfig> = { ...base.sources };
+  for (const [type, source] of Object.entries(overlay.sources)) {
+    sources[type] = { ...sources[type], ...source };
+  }
+  const groups: Record<string, GroupConfig> = { ...base.groups };
+  for (const [name, group] of Object.entries(overlay.groups)) {
+    groups[name] = { ...groups[name], ...group };
+  }
+  return {
+    global: { ...base.global, ...overlay.global },
+    packages,
+    sources,
+    groups,
+    plugins: { ...base.plugins, ...overlay.plugins },
+    profiles: { ...base.profiles, ...overlay.profiles },
+  };
+}
+
+/** User-level defaults, following XDG conventions. */
+export function userConfigPath(): string {
+  const xdg = Deno.env.get("XDG_CONFIG_HOME");
+  if (xdg !== undefined && xdg !== "") {
+    return join(xdg, "treeupdt", "config.json");
+  }
+  const home = Deno.env.get("HOME") ?? ".";
+  return join(home, ".config", "treeupdt", "config.json");
+}
+
+async function loadConfigFile(path: string): Promise<Config | null> {
+  if (!(await fileExists(path))) return null;
+  const parsed: unknown = JSON.parse(await Deno.readTextFile(path));
+  if (configVersion(parsed) < currentConfigVersion) {
+    warn(
+      `${path} uses config-version ${configVersion(parsed)}; ` +
+        `run \`treeupdt config migrate\` to update it`,
+    );
+  }
+  return parseConfig(parsed, path);
+}
+
+/** Global settings that may be overridden from the environment. */
+const envOverrideKeys: Readonly<Record<string, string>> = {
+  TREEUPDT_COMMIT_TEMPLATE: "commit-template",
+  TREEUPDT_MINIMUM_RELEASE_AGE: "minimum-release-age",
+  TREEUPDT_STRATEGY: "strategy",
+  TREEUPDT_CACHE_DIR: "cache-dir",
+};
+
+function envOverrides(): Config {
+  const global: Record<string, unknown> = {};
+  for (const [env, key] of Object.entries(envOverrideKeys)) {
+    const value = Deno.env.get(env);
+    if (value !== undefined && value !== "") {
+      global[key] = value;
+    }
+  }
+  return parseConfig({ global }, "environment");
+}
+
+/**
+ * Load configuration in layers, later layers winning per key: built-in
+ * defaults, then the user config (`~/.config/treeupdt/config.json`), then the
+ * project `.treeupdt.json` in `dir`, then `TREEUPDT_*` environment variables.
+ * CLI flags override all of these at the command level.
+ */
+export async function loadConfig(dir: string): Promise<Config> {
+  let config = defaultConfig;
+  const user = await loadConfigFile(userConfigPath());
+  if (user !== null) config = mergeConfig(config, user);
+  const project = await loadConfigFile(join(dir, configFileName));
+  if (project !== null) config = mergeConfig(config, project);
+  const merged = mergeConfig(config, envOverrides());
+  if (merged.global.cacheDir !== undefined) {
+    setCacheDirOverride(merged.global.cacheDir);
+  }
+  return merged;
+}
+
+/**
+ * Nested per-directory config discovery for monorepos: a `.treeupdt.json` in
+ * a subdirectory applies to files under it, layered on top of the root
+ * config (and any configs in intermediate directories).
+ */
+export class ConfigTree {
+  readonly #root: string;
+  readonly #base: Config;
+  readonly #byDir = new Map<string, Promise<Config>>();
+
+  constructor(root: string, base: Config) {
+    this.#root = root;
+    this.#base = base;
+  }
+
+  /** Effective config for a file path relative to the tree root. */
+  forFile(file: string): Promise<Config> {
+    const dir = dirname(file);
+    return this.#forDir(dir === "." ? "" : dir);
+  }
+
+  #forDir(dir: string): Promise<Config> {
+    let resolved = this.#byDir.get(dir);
+    if (resolved === undefined) {
+      resolved = this.#resolve(dir);
+      this.#byDir.set(dir, resolved);
+    }
+    return resolved;
+  }
+
+  async #resolve(dir: string): Promise<Config> {
+    if (dir === "") return this.#base;
+    const parent = dirname(dir);
+    const inherited = await this.#forDir(parent === "." ? "" : parent);
+    const nested = await loadConfigFile(join(this.#root, dir, configFileName));
+    return nested === null ? inherited : mergeConfig(inherited, nested);
+  }
+}
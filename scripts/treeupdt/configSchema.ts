@@ -0,0 +1,238 @@
+import type { JsonValue } from "../updater/jsonFile.ts";
+import { strategies } from "./strategy.ts";
+
+const durationPattern = "^[0-9]+[dhms]$";
+
+const strategySchema: JsonValue = {
+  type: "string",
+  enum: [...strategies],
+  description: "How far a package may be bumped.",
+};
+
+const stringArraySchema: JsonValue = {
+  type: "array",
+  items: { type: "string" },
+};
+
+/**
+ * JSON Schema for `.treeupdt.json`, kept in lockstep with `config.ts` so
+ * editors can validate and auto-complete the file. Emitted by
+ * `treeupdt config schema`.
+ */
+export function configJsonSchema(): JsonValue {
+  return {
+    $schema: "http://json-schema.org/draft-07/schema#",
+    title: "treeupdt configuration",
+    type: "object",
+    additionalProperties: false,
+    properties: {
+      profiles: {
+        type: "object",
+        description: "Named overlays selected with --profile.",
+        additionalProperties: {
+          type: "object",
+          properties: {
+            global: { "$ref": "#/properties/global" },
+            packages: { "$ref": "#/properties/packages" },
+            sources: { "$ref": "#/properties/sources" },
+          },
+        },
+      },
+      "config-version": {
+        type: "integer",
+        description: "Schema version; `config migrate` keeps it current.",
+      },
+      global: {
+        type: "object",
+        additionalProperties: false,
+        properties: {
+          "commit-template": {
+            type: "string",
+            description: "Commit message template with {{name}}, {{old}}, {{new}} variables.",
+          },
+          "minimum-release-age": {
+            type: "string",
+            pattern: durationPattern,
+            description: "Cooldown like 7d: younger releases are not reported or applied.",
+          },
+          "strategy": strategySchema,
+          "cache-backend": {
+            type: "string",
+            enum: ["files", "indexed"],
+            description: "Response cache backend: one file per key, or a single indexed file.",
+          },
+          "cache-ttl": {
+            type: "string",
+            pattern: durationPattern,
+            description: "Default cache TTL like 1h; per-source cache-ttl overrides it.",
+          },
+          "cache-dir": {
+            type: "string",
+            description: "Cache location override; TREEUPDT_CACHE_DIR wins over the config.",
+          },
+          "strategy-by-type": {
+            type: "object",
+            additionalProperties: strategySchema,
+            description: "Default strategy per file type, e.g. nix = latest, cargo = conservative.",
+          },
+          "source-priority": {
+            type: "array",
+            items: { type: "string" },
+            description: "Preferred source order when a package has several hints.",
+          },
+          "exclude-paths": {
+            type: "array",
+            items: { type: "string" },
+            description: "Globs (relative to the root) excluded from scanning.",
+          },
+          "only-packages": {
+            type: "array",
+            items: { type: "string" },
+            description: "When set, only packages matching one of these name globs are handled.",
+          },
+          "deny-packages": {
+            type: "array",
+            items: { type: "string" },
+            description: "Packages matching one of these name globs are skipped repo-wide.",
+          },
+          "webhooks": {
+            type: "array",
+            items: {
+              type: "object",
+              additionalProperties: false,
+              required: ["url"],
+              properties: {
+                "url": { type: "string", description: "Endpoint URL; supports ${VAR}." },
+                "format": {
+                  type: "string",
+                  enum: ["json", "slack", "discord", "matrix"],
+                  description: "Payload shape. Defaults to json.",
+                },
+              },
+            },
+            description: "Targets `check --notify` posts summaries to.",
+          },
+          "filters": {
+            type: "object",
+            additionalProperties: false,
+            properties: {
+              "file-types": stringArraySchema,
+              "sources": stringArraySchema,
+              "name-patterns": stringArraySchema,
+            },
+          },
+        },
+      },
+      packages: {
+        type: "object",
+        additionalProperties: {
+          type: "object",
+          additionalProperties: false,
+          properties: {
+            "minimum-release-age": { type: "string", pattern: durationPattern },
+            "pin-version": {
+              type: "string",
+              description: "Hold the package at exactly this version.",
+            },
+            "strategy": strategySchema,
+            "preferred-source": {
+              type: "array",
+              items: { type: "string" },
+              description: "Sources to try in order, falling through on error or no releases.",
+            },
+            "schedule": {
+              type: "string",
+              description: "Cadence window: daily, weekly, monthly, quarterly, or cron.",
+            },
+            "extract-version": {
+              type: "string",
+              description: "Regex whose first capture group extracts the version from a tag.",
+            },
+            "tag-template": {
+              type: "string",
+              description:
+                "Template rendering a version back into a tag, e.g. release/{{version}}.",
+            },
+          },
+        },
+      },
+      groups: {
+        type: "object",
+        additionalProperties: {
+          type: "object",
+          additionalProperties: false,
+          properties: {
+            "packages": {
+              type: "array",
+              items: { type: "string" },
+              description: "Name globs selecting members.",
+            },
+            "files": {
+              type: "array",
+              items: { type: "string" },
+              description: "File globs selecting members.",
+            },
+            "sources": {
+              type: "array",
+              items: { type: "string" },
+              description: "Source types selecting members.",
+            },
+            "strategy": strategySchema,
+            "commit-template": { type: "string" },
+            "schedule": {
+              type: "string",
+              description: "Cadence window shared by the group's members.",
+            },
+          },
+        },
+      },
+      plugins: {
+        type: "object",
+        description: "WASM plugin modules extending scanners and sources.",
+        additionalProperties: {
+          type: "object",
+          additionalProperties: false,
+          required: ["path", "kind"],
+          properties: {
+            "path": {
+              type: "string",
+              description: "Path to the compiled WASM module.",
+            },
+            "kind": { type: "string", enum: ["scanner", "source"] },
+            "file-type": {
+              type: "string",
+              description: "Scanner plugins: file type label for the packages they produce.",
+            },
+            "files": {
+              type: "array",
+              items: { type: "string" },
+              description: "Scanner plugins: manifest globs the plugin handles.",
+            },
+            "source-type": {
+              type: "string",
+              description: "Source plugins: the source type they serve.",
+            },
+          },
+        },
+      },
+      sources: {
+        type: "object",
+        additionalProperties: {
+          type: "object",
+          additionalProperties: false,
+          properties: {
+            "token": { type: "string" },
+            "token-env": {
+              type: "string",
+              description: "Environment variable to read the token from.",
+            },
+            "base-url": { type: "string", format: "uri" },
+            "cache-ttl": { type: "string", pattern: durationPattern },
+            "concurrency": { type: "integer", minimum: 1 },
+            "enabled": { type: "boolean" },
+          },
+        },
+      },
+    },
+  };
+}
@@ -0,0 +1,49 @@
+import { assertArray, assertRecord } from "../updater/assert.ts";
+import { fetchJson } from "./http.ts";
+
+export type EolCycle = Readonly<{
+  cycle: string;
+  /** ISO date the cycle reaches end of life, or null when none is scheduled. */
+  eolDate: string | null;
+  /** Whether the cycle is already end-of-life. */
+  eol: boolean;
+}>;
+
+function parseCycle(data: unknown, context: string): EolCycle | null {
+  assertRecord(data, `${context}: expected object`);
+  const cycle = data["cycle"];
+  if (typeof cycle !== "string" && typeof cycle !== "number") return null;
+
+  const eol = data["eol"];
+  if (typeof eol === "boolean") {
+    return { cycle: String(cycle), eolDate: null, eol };
+  }
+  if (typeof eol === "string") {
+    return { cycle: String(cycle), eolDate: eol, eol: Date.parse(eol) <= Date.now() };
+  }
+  return null;
+}
+
+/** Query endoflife.date for a product's release cycles (`go`, `nodejs`, ...). */
+export async function fetchEolCycles(product: string): Promise<EolCycle[]> {
+  const data = await fetchJson(`https://endoflife.date/api/${product}.json`);
+  assertArray(data, `endoflife.date ${product}`);
+
+  const cycles: EolCycle[] = [];
+  for (const [i, raw] of data.entries()) {
+    const parsed = parseCycle(raw, `endoflife.date ${product}[${i}]`);
+    if (parsed) cycles.push(parsed);
+  }
+  return cycles;
+}
+
+/** Match a pinned version like `1.21.4` or `20` against its release cycle. */
+export function findCycle(cycles: readonly EolCycle[], version: string): EolCycle | null {
+  const cleaned = version.replace(/^v/, "");
+  let best: EolCycle | null = null;
+  for (const cycle of cycles) {
+    if (cleaned !== cycle.cycle && !cleaned.startsWith(`${cycle.cycle}.`)) continue;
+    if (best === null || cycle.cycle.length > best.cycle.length) best = cycle;
+  }
+  return best;
+}
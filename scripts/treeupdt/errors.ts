@@ -0,0 +1,45 @@
+import { HttpStatusError } from "./http.ts";
+
+/**
+ * Stable machine-readable failure codes, surfaced per package in JSON output
+ * so automation can tell "private repo needs a token" from "network down"
+ * without parsing error prose.
+ */
+export const errorCodes = [
+  "not-found",
+  "rate-limited",
+  "auth-required",
+  "network-error",
+  "parse-error",
+  "unsupported-file",
+  "offline-miss",
+  "internal",
+] as const;
+
+export type ErrorCode = (typeof errorCodes)[number];
+
+/** Error carrying one of the stable codes; prefer this for new failures. */
+export class TreeupdtError extends Error {
+  readonly code: ErrorCode;
+
+  constructor(code: ErrorCode, message: string) {
+    super(message);
+    this.name = "TreeupdtError";
+    this.code = code;
+  }
+}
+
+/** Best-effort code for any thrown value, `internal` when nothing matches. */
+export function classifyError(err: unknown): ErrorCode {
+  if (err instanceof TreeupdtError) return err.code;
+  if (err instanceof HttpStatusError) {
+    if (err.status === 404 || err.status === 410) return "not-found";
+    if (err.status === 429) return "rate-limited";
+    if (err.status === 401 || err.status === 403) return "auth-required";
+    return "network-error";
+  }
+  if (err instanceof SyntaxError) return "parse-error";
+  // fetch() reports connection-level failures as TypeError.
+  if (err instanceof TypeError) return "network-error";
+  return "internal";
+}
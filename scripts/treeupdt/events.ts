@@ -0,0 +1,23 @@
+import type { Package, UpdateEntry } from "./types.ts";
+
+/**
+ * Progress events emitted by the check pipeline, so embedders (editors, a
+ * future TUI) can render progress without parsing text output. Listeners are
+ * called synchronously in pipeline order; keep them cheap.
+ */
+export type TreeupdtEvent =
+  | Readonly<{ kind: "scan-started"; root: string }>
+  | Readonly<{ kind: "package-found"; package: Package }>
+  | Readonly<{ kind: "check-started"; name: string; file: string }>
+  | Readonly<
+    { kind: "check-finished"; name: string; file: string; entries: readonly UpdateEntry[] }
+  >
+  | Readonly<
+    { kind: "update-applied"; file: string; name: string; fromVersion: string; toVersion: string }
+  >
+  | Readonly<{ kind: "error"; name: string; file: string; message: string }>;
+
+export type EventListener = (event: TreeupdtEvent) => void;
+
+/** No-op listener, standing in when the caller doesn't subscribe. */
+export const nullListener: EventListener = () => {};
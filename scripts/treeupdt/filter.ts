@@ -0,0 +1,59 @@
+import { matchGlob } from "./glob.ts";
+import type { Package } from "./types.ts";
+
+/**
+ * Dimension-based package filter. Values within a dimension are alternatives
+ * (OR); dimensions combine conjunctively (AND). Empty dimensions match
+ * everything.
+ */
+export type Filter = Readonly<{
+  fileTypes: readonly string[];
+  sources: readonly string[];
+  /** Globs matched against display names and bare `PackageId` names. */
+  namePatterns: readonly string[];
+}>;
+
+export const emptyFilter: Filter = {
+  fileTypes: [],
+  sources: [],
+  namePatterns: [],
+};
+
+export function mergeFilters(a: Filter, b: Filter): Filter {
+  return {
+    fileTypes: [...a.fileTypes, ...b.fileTypes],
+    sources: [...a.sources, ...b.sources],
+    namePatterns: [...a.namePatterns, ...b.namePatterns],
+  };
+}
+
+/** Allow/deny list semantics for `only-packages` / `deny-packages`. */
+export function allowedByLists(
+  name: string,
+  only: readonly string[],
+  deny: readonly string[],
+): boolean {
+  if (deny.some((pattern) => matchGlob(pattern, name))) return false;
+  return only.length === 0 || only.some((pattern) => matchGlob(pattern, name));
+}
+
+export function matchesFilter(pkg: Package, filter: Filter): boolean {
+  if (filter.fileTypes.length > 0 && !filter.fileTypes.includes(pkg.fileType)) {
+    return false;
+  }
+  if (
+    filter.sources.length > 0 &&
+    !pkg.sourceHints.some((hint) => filter.sources.includes(hint.source))
+  ) {
+    return false;
+  }
+  if (
+    filter.namePatterns.length > 0 &&
+    !filter.namePatterns.some((pattern) =>
+      matchGlob(pattern, pkg.name) || matchGlob(pattern, pkg.id.name)
+    )
+  ) {
+    return false;
+  }
+  return true;
+}
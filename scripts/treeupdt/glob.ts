@@ -0,0 +1,85 @@
+const regexSpecials = /[.+^${}()|[\]\\]/g;
+
+/** Translate a glob (or brace alternative) into a RegExp body. */
+function globBody(pattern: string): string {
+  let out = "";
+  let i = 0;
+  while (i < pattern.length) {
+    const ch = pattern[i] as string;
+    if (ch === "*") {
+      if (pattern[i + 1] === "*") {
+        if (pattern[i + 2] === "/") {
+          // `**/` spans any number of path segments, including none.
+          out += "(?:[^/]*/)*";
+          i += 3;
+        } else {
+          out += ".*";
+          i += 2;
+        }
+      } else {
+        out += "[^/]*";
+        i += 1;
+      }
+    } else if (ch === "?") {
+      out += "[^/]";
+      i += 1;
+    } else if (ch === "[") {
+      let j = i + 1;
+      let body = "";
+      if (pattern[j] === "!" || pattern[j] === "^") {
+        body += "^";
+        j += 1;
+      }
+      if (pattern[j] === "]") {
+        body += "\\]";
+        j += 1;
+      }
+      while (j < pattern.length && pattern[j] !== "]") {
+        const inner = pattern[j] as string;
+        body += inner === "\\" ? "\\\\" : inner;
+        j += 1;
+      }
+      if (j >= pattern.length) {
+        // Unterminated class: treat the bracket literally, like globset does.
+        out += "\\[";
+        i += 1;
+      } else {
+        out += `[${body}]`;
+        i = j + 1;
+      }
+    } else if (ch === "{") {
+      const close = pattern.indexOf("}", i);
+      if (close === -1) {
+        out += "\\{";
+        i += 1;
+      } else {
+        const alternatives = pattern.slice(i + 1, close).split(",");
+        out += `(?:${alternatives.map(globBody).join("|")})`;
+        i = close + 1;
+      }
+    } else {
+      out += ch.replace(regexSpecials, "\\$&");
+      i += 1;
+    }
+  }
+  return out;
+}
+
+const compiled = new Map<string, RegExp>();
+
+/**
+ * Compile a glob into an anchored RegExp. Supports `*` (within a segment),
+ * `?`, `**` (across segments), `[...]` character classes, and `{a,b}`
+ * alternation, so patterns like `**\/node_modules/**` work as expected.
+ */
+export function globToRegExp(pattern: string): RegExp {
+  const cached = compiled.get(pattern);
+  if (cached) return cached;
+  const regex = new RegExp(`^${globBody(pattern)}$`);
+  compiled.set(pattern, regex);
+  return regex;
+}
+
+export function matchGlob(pattern: string, path: string): boolean {
+  return globToRegExp(pattern).test(path);
+}
@@ -0,0 +1,60 @@
+import { isRecord } from "../updater/assert.ts";
+import { fileExists } from "../updater/fs.ts";
+
+/** Append-only JSONL journal of applied updates, one entry per line. */
+export const historyFileName = ".treeupdt-history.jsonl";
+
+export type HistoryEntry = Readonly<{
+  /** ISO timestamp of when the edit landed. */
+  timestamp: string;
+  file: string;
+  name: string;
+  oldVersion: string;
+  newVersion: string;
+  /** Shared by every edit applied in the same run, for grouped rollbacks. */
+  transactionId: string;
+  /** Only present when treeupdt itself made a git commit for the edit. */
+  commitSha?: string;
+}>;
+
+/** One id per CLI invocation; edits from a single run share it. */
+export function newTransactionId(): string {
+  return crypto.randomUUID().slice(0, 8);
+}
+
+export async function appendHistory(entry: HistoryEntry): Promise<void> {
+  await Deno.writeTextFile(historyFileName, `${JSON.stringify(entry)}\n`, { append: true });
+}
+
+export async function readHistory(): Promise<HistoryEntry[]> {
+  if (!await fileExists(historyFileName)) return [];
+  const entries: HistoryEntry[] = [];
+  const lines = (await Deno.readTextFile(historyFileName)).split("\n");
+  for (const [i, line] of lines.entries()) {
+    if (line.trim() === "") continue;
+    let data: unknown;
+    try {
+      data = JSON.parse(line);
+    } catch {
+      throw new Error(`${historyFileName}:${i + 1}: not valid JSON`);
+    }
+    if (
+      !isRecord(data) || typeof data["timestamp"] !== "string" ||
+      typeof data["file"] !== "string" || typeof data["name"] !== "string" ||
+      typeof data["oldVersion"] !== "string" || typeof data["newVersion"] !== "string" ||
+      typeof data["transactionId"] !== "string"
+    ) {
+      throw new Error(`${historyFileName}:${i + 1}: malformed history entry`);
+    }
+    entries.push({
+      timestamp: data["timestamp"],
+      file: data["file"],
+      name: data["name"],
+      oldVersion: data["oldVersion"],
+      newVersion: data["newVersion"],
+      transactionId: data["transactionId"],
+      ...(typeof data["commitSha"] === "string" ? { commitSha: data["commitSha"] } : {}),
+    });
+  }
+  return entries;
+}
@@ -0,0 +1,186 @@
+export type FetchOptions = Readonly<{
+  headers?: HeadersInit;
+  /** Defaults to GET; webhooks POST their payloads through the same layer. */
+  method?: string;
+  body?: string;
+  timeoutMs?: number;
+  /** Retry attempts after the first request. Defaults to 3. */
+  retries?: number;
+  /** Cancellation: aborts the in-flight request and skips any retries. */
+  signal?: AbortSignal;
+}>;
+
+const defaultRetries = 3;
+const baseDelayMs = 500;
+const maxDelayMs = 30_000;
+
+function backoffDelayMs(attempt: number): number {
+  const exponential = baseDelayMs * 2 ** attempt;
+  const jitter = Math.random() * baseDelayMs;
+  return Math.min(exponential + jitter, maxDelayMs);
+}
+
+/** Server-instructed delay from Retry-After / X-RateLimit-Reset, if present. */
+function rateLimitDelayMs(res: Response): number | null {
+  const retryAfter = res.headers.get("Retry-After");
+  if (retryAfter !== null) {
+    const seconds = Number(retryAfter);
+    if (Number.isFinite(seconds)) return Math.max(seconds * 1000, 0);
+    const date = Date.parse(retryAfter);
+    if (!Number.isNaN(date)) return Math.max(date - Date.now(), 0);
+  }
+
+  const reset = res.headers.get("X-RateLimit-Reset");
+  const remaining = res.headers.get("X-RateLimit-Remaining");
+  if (reset !== null && remaining === "0") {
+    const resetMs = Number(reset) * 1000;
+    if (Number.isFinite(resetMs)) return Math.max(resetMs - Date.now(), 0);
+  }
+  return null;
+}
+
+function isRetryableStatus(res: Response): boolean {
+  if (res.status === 429 || res.status >= 500) return true;
+  // GitHub reports rate limiting as 403 with rate-limit headers.
+  return res.status === 403 && res.headers.get("X-RateLimit-Remaining") === "0";
+}
+
+function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+async function fetchOnce(url: string, opts: FetchOptions): Promise<Response> {
+  const timeoutMs = opts.timeoutMs ?? 30_000;
+  const controller = new AbortController();
+  const id = setTimeout(() => controller.abort(), timeoutMs);
+  const abort = () => controller.abort();
+  if (opts.signal?.aborted === true) {
+    controller.abort();
+  }
+  opts.signal?.addEventListener("abort", abort, { once: true });
+  try {
+    return await fetch(url, {
+      headers: opts.headers,
+      ...(opts.method !== undefined ? { method: opts.method } : {}),
+      ...(opts.body !== undefined ? { body: opts.body } : {}),
+      signal: controller.signal,
+    });
+  } finally {
+    clearTimeout(id);
+    opts.signal?.removeEventListener("abort", abort);
+  }
+}
+
+/**
+ * Shared HTTP layer for all sources: retries transient failures with
+ * exponential backoff and jitter, and honors `Retry-After` /
+ * `X-RateLimit-Reset` when a server asks us to slow down.
+ */
+export async function fetchWithRetry(url: string, opts: FetchOptions = {}): Promise<Response> {
+  const retries = opts.retries ?? defaultRetries;
+  let lastError: unknown = null;
+
+  for (let attempt = 0; attempt <= retries; attempt += 1) {
+    let res: Response;
+    try {
+      res = await fetchOnce(url, opts);
+    } catch (err) {
+      lastError = err;
+      if (attempt === retries || opts.signal?.aborted === true) break;
+      await sleep(backoffDelayMs(attempt));
+      continue;
+    }
+
+    if (!isRetryableStatus(res) || attempt === retries || opts.signal?.aborted === true) {
+      return res;
+    }
+
+    await res.body?.cancel().catch(() => undefined);
+    const serverDelay = rateLimitDelayMs(res);
+    await sleep(Math.min(serverDelay ?? backoffDelayMs(attempt), maxDelayMs));
+  }
+
+  throw new Error(
+    `Request failed after ${retries + 1} attempts: ${url}` +
+      (lastError instanceof Error ? ` (${lastError.message})` : ""),
+  );
+}
+
+/** Non-2xx response; carries the status so callers can react to 404 etc. */
+export class HttpStatusError extends Error {
+  readonly status: number;
+
+  constructor(status: number, message: string) {
+    super(message);
+    this.name = "HttpStatusError";
+    this.status = status;
+  }
+}
+
+export async function fetchText(url: string, opts: FetchOptions = {}): Promise<string> {
+  const res = await fetchWithRetry(url, opts);
+  if (!res.ok) {
+    const body = await res.text().catch(() => "");
+    throw new HttpStatusError(
+      res.status,
+      `HTTP ${res.status} ${res.statusText} for ${url}${body ? `\n${body}` : ""}`,
+    );
+  }
+  return await res.text();
+}
+
+export async function fetchJson(url: string, opts: FetchOptions = {}): Promise<unknown> {
+  const text = await fetchText(url, opts);
+  const parsed: unknown = JSON.parse(text);
+  return parsed;
+}
+
+/** Cached response validators for conditional requests. */
+export type Validators = Readonly<{
+  etag?: string;
+  lastModified?: string;
+}>;
+
+export type ConditionalText =
+  | Readonly<{ notModified: true }>
+  | Readonly<{ notModified: false; text: string; etag?: string; lastModified?: string }>;
+
+/**
+ * Fetch with `If-None-Match` / `If-Modified-Since` from a previous response's
+ * validators. A 304 means the cached copy is still good and no body was
+ * transferred; otherwise the fresh body comes back with new validators.
+ */
+export async function fetchTextConditional(
+  url: string,
+  opts: FetchOptions = {},
+  validators: Validators = {},
+): Promise<ConditionalText> {
+  const headers = new Headers(opts.headers);
+  if (validators.etag !== undefined) {
+    headers.set("If-None-Match", validators.etag);
+  }
+  if (validators.lastModified !== undefined) {
+    headers.set("If-Modified-Since", validators.lastModified);
+  }
+
+  const res = await fetchWithRetry(url, { ...opts, headers });
+  if (res.status === 304) {
+    await res.body?.cancel().catch(() => undefined);
+    return { notModified: true };
+  }
+  if (!res.ok) {
+    const body = await res.text().catch(() => "");
+    throw new HttpStatusError(
+      res.status,
+      `HTTP ${res.status} ${res.statusText} for ${url}${body ? `\n${body}` : ""}`,
+    );
+  }
+  const etag = res.headers.get("ETag");
+  const lastModified = res.headers.get("Last-Modified");
+  return {
+    notModified: false,
+    text: await res.text(),
+    ...(etag !== null ? { etag } : {}),
+    ...(lastModified !== null ? { lastModified } : {}),
+  };
+}
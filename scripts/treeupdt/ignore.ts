@@ -0,0 +1,69 @@
+import { matchGlob } from "./glob.ts";
+
+export const ignoreFileName = ".treeupdtignore";
+
+/** One line of a `.treeupdtignore` file, gitignore-style. */
+export type IgnoreRule = Readonly<{
+  pattern: string;
+  /** `!pattern` re-includes a previously ignored path. */
+  negated: boolean;
+  /** `pattern/` only applies to directories. */
+  dirOnly: boolean;
+  /** Patterns containing (or starting with) `/` match from the file's directory. */
+  anchored: boolean;
+}>;
+
+export function parseIgnoreRules(content: string): IgnoreRule[] {
+  const rules: IgnoreRule[] = [];
+  for (const rawLine of content.split("\n")) {
+    let line = rawLine.replace(/\r$/, "").trimEnd();
+    if (line === "" || line.startsWith("#")) continue;
+    let negated = false;
+    if (line.startsWith("!")) {
+      negated = true;
+      line = line.slice(1);
+    }
+    let dirOnly = false;
+    if (line.endsWith("/")) {
+      dirOnly = true;
+      line = line.slice(0, -1);
+    }
+    let anchored = line.includes("/");
+    if (line.startsWith("/")) {
+      anchored = true;
+      line = line.slice(1);
+    }
+    if (line === "") continue;
+    rules.push({ pattern: line, negated, dirOnly, anchored });
+  }
+  return rules;
+}
+
+/** Whether a rule applies to `path`, relative to the ignore file's directory. */
+export function ruleMatches(rule: IgnoreRule, path: string, isDirectory: boolean): boolean {
+  if (rule.dirOnly && !isDirectory) return false;
+  // Unanchored patterns match in any subdirectory, like gitignore.
+  const pattern = rule.anchored ? rule.pattern : `**/${rule.pattern}`;
+  return matchGlob(pattern, path) || matchGlob(`${pattern}/**`, path);
+}
+
+/**
+ * Evaluate a path against rule sets from outermost to innermost directory;
+ * within each set the last matching rule wins, so negations can re-include.
+ */
+export function isIgnored(
+  ruleSets: readonly (readonly [string, readonly IgnoreRule[]])[],
+  pathFor: (base: string) => string,
+  isDirectory: boolean,
+): boolean {
+  let ignored = false;
+  for (const [base, rules] of ruleSets) {
+    const rel = pathFor(base);
+    for (const rule of rules) {
+      if (ruleMatches(rule, rel, isDirectory)) {
+        ignored = !rule.negated;
+      }
+    }
+  }
+  return ignored;
+}
@@ -0,0 +1,192 @@
+import { isRecord } from "../../updater/assert.ts";
+import type { JsonValue } from "../../updater/jsonFile.ts";
+import { scheduleKeywords } from "../schedule.ts";
+import type { ImportResult } from "./renovate.ts";
+
+type IgnoreRule = { dependencyName?: string; updateTypes: string[] };
+
+type UpdateBlock = {
+  ecosystem?: string;
+  directory?: string;
+  openPullRequestsLimit?: number;
+  hasSchedule: boolean;
+  scheduleInterval?: string;
+  ignore: IgnoreRule[];
+};
+
+const ecosystemFileTypes: Readonly<Record<string, string>> = {
+  cargo: "cargo",
+  npm: "npm",
+  gomod: "go",
+};
+
+/** Manifest globs selecting an ecosystem's files for a schedule group. */
+const ecosystemManifestGlobs: Readonly<Record<string, string>> = {
+  cargo: "**/Cargo.toml",
+  npm: "**/package.json",
+  gomod: "**/go.mod",
+};
+
+function unquote(value: string): string {
+  return value.replace(/^["']/, "").replace(/["']$/, "");
+}
+
+function inlineList(value: string): string[] {
+  const inner = value.replace(/^\[/, "").replace(/\]$/, "");
+  return inner.split(",").map((item) => unquote(item.trim())).filter((item) => item !== "");
+}
+
+/**
+ * Parse the subset of `dependabot.yml` we can translate. Dependabot configs
+ * are shallow and regular enough that a line-based reader beats pulling in a
+ * YAML dependency for one file.
+ */
+export function parseDependabot(content: string): UpdateBlock[] {
+  const blocks: UpdateBlock[] = [];
+  let block: UpdateBlock | null = null;
+  let ignore: IgnoreRule | null = null;
+  let section = "";
+
+  for (const raw of content.split("\n")) {
+    const line = raw.replace(/#.*$/, "").trimEnd();
+    const trimmed = line.trim();
+    if (trimmed === "") continue;
+    const indent = line.length - line.trimStart().length;
+
+    if (indent <= 2 && trimmed.startsWith("- ")) {
+      block = { hasSchedule: false, ignore: [] };
+      blocks.push(block);
+      ignore = null;
+      section = "";
+    }
+    if (block === null) continue;
+
+    const entry = trimmed.replace(/^-\s*/, "");
+    const match = entry.match(/^([a-z-]+):\s*(.*)$/);
+    if (!match?.[1]) continue;
+    const key = match[1];
+    const value = match[2] ?? "";
+
+    if (section === "ignore" && indent > 4) {
+      if (trimmed.startsWith("- ")) {
+        ignore = { updateTypes: [] };
+        block.ignore.push(ignore);
+      }
+      if (ignore === null) continue;
+      if (key === "dependency-name") {
+        ignore.dependencyName = unquote(value);
+      } else if (key === "update-types") {
+        ignore.updateTypes = inlineList(value);
+      }
+      continue;
+    }
+    if (section === "schedule" && indent > 4) {
+      block.hasSchedule = true;
+      if (key === "interval") {
+        block.scheduleInterval = unquote(value);
+      }
+      continue;
+    }
+
+    // Keys directly on the update block (the `- ` line itself or indent 4).
+    if (value === "") {
+      section = key;
+    } else if (key === "package-ecosystem") {
+      block.ecosystem = unquote(value);
+    } else if (key === "directory") {
+      block.directory = unquote(value);
+    } else if (key === "open-pull-requests-limit") {
+      const limit = Number(value);
+      if (Number.isInteger(limit)) block.openPullRequestsLimit = limit;
+    }
+  }
+  return blocks;
+}
+
+/**
+ * Translate `.github/dependabot.yml` into treeupdt config: ecosystems become
+ * a file-type filter, ignored dependencies become `deny-packages`, major-only
+ * ignores become per-package `conservative` strategies, and schedule intervals
+ * become per-ecosystem group schedules.
+ */
+export function importDependabot(content: string): ImportResult {
+  const warnings: string[] = [];
+  const fileTypes: string[] = [];
+  const denyPackages: string[] = [];
+  const packages: Record<string, JsonValue> = {};
+  const groups: Record<string, JsonValue> = {};
+  let unmappedEcosystem = false;
+
+  for (const block of parseDependabot(content)) {
+    const ecosystem = block.ecosystem ?? "<missing>";
+    if (block.openPullRequestsLimit === 0) {
+      warnings.push(`${ecosystem}: open-pull-requests-limit is 0; ecosystem skipped`);
+      continue;
+    }
+    const fileType = ecosystemFileTypes[ecosystem];
+    if (fileType === undefined) {
+      warnings.push(`${ecosystem}: no treeupdt scanner for this ecosystem; skipped`);
+      unmappedEcosystem = true;
+      continue;
+    }
+    if (!fileTypes.includes(fileType)) fileTypes.push(fileType);
+    // `schedule.interval` keywords map one-to-one onto treeupdt schedules,
+    // scoped to the ecosystem's manifests through a group.
+    if (block.scheduleInterval !== undefined) {
+      if ((scheduleKeywords as readonly string[]).includes(block.scheduleInterval)) {
+        groups[ecosystem] = {
+          files: [ecosystemManifestGlobs[ecosystem] ?? "**"],
+          schedule: block.scheduleInterval,
+        };
+      } else {
+        warnings.push(
+          `${ecosystem}: schedule interval ${block.scheduleInterval} has no ` +
+            `treeupdt keyword (expected ${scheduleKeywords.join(", ")}); dropped`,
+        );
+      }
+    } else if (block.hasSchedule) {
+      warnings.push(`${ecosystem}: schedule block has no interval; dropped`);
+    }
+    if (block.directory !== undefined && block.directory !== "/") {
+      warnings.push(
+        `${ecosystem}: directory ${block.directory} dropped; treeupdt scans the whole tree`,
+      );
+    }
+
+    for (const rule of block.ignore) {
+      if (rule.dependencyName === undefined) continue;
+      const majorOnly = rule.updateTypes.length > 0 &&
+        rule.updateTypes.every((type) => type === "version-update:semver-major");
+      if (rule.updateTypes.length === 0) {
+        denyPackages.push(rule.dependencyName);
+      } else if (majorOnly && !/[*?[{]/.test(rule.dependencyName)) {
+        packages[rule.dependencyName] = { strategy: "conservative" };
+      } else {
+        warnings.push(
+          `${ecosystem}: ignore rule for ${rule.dependencyName} not translatable; dropped`,
+        );
+      }
+    }
+  }
+
+  const config: Record<string, JsonValue> = {};
+  // A filter listing every known file type would be a no-op that blocks
+  // future scanners, so only emit one when it actually narrows the scan.
+  if (fileTypes.length > 0 && unmappedEcosystem) {
+    config["global"] = { filters: { "file-types": fileTypes } };
+  }
+  if (denyPackages.length > 0) {
+    const global = config["global"];
+    config["global"] = {
+      ...(isRecord(global) ? global : {}),
+      "deny-packages": [...new Set(denyPackages)],
+    };
+  }
+  if (Object.keys(packages).length > 0) {
+    config["packages"] = packages;
+  }
+  if (Object.keys(groups).length > 0) {
+    config["groups"] = groups;
+  }
+  return { config, warnings };
+}
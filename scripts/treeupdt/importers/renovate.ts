@@ -0,0 +1,93 @@
+import type { JsonValue } from "../../updater/jsonFile.ts";
+import { isRecord } from "../../updater/assert.ts";
+
+export type ImportResult = Readonly<{
+  /** Raw `.treeupdt.json` fragment, ready to merge and write. */
+  config: Record<string, JsonValue>;
+  /** Settings that have no treeupdt equivalent (yet). */
+  warnings: string[];
+}>;
+
+function stringArray(value: unknown): string[] {
+  return Array.isArray(value)
+    ? value.filter((item): item is string => typeof item === "string")
+    : [];
+}
+
+/** Renovate `matchPackagePatterns` are regexes; approximate them as globs. */
+function patternToGlob(pattern: string, warnings: string[]): string {
+  if (/[\\()|+]/.test(pattern)) {
+    warnings.push(`packageRules: pattern ${pattern} uses regex syntax; imported loosely as a glob`);
+  }
+  const stripped = pattern.replace(/^\^/, "").replace(/\$$/, "");
+  const anchoredStart = pattern.startsWith("^");
+  const anchoredEnd = pattern.endsWith("$");
+  return `${anchoredStart ? "" : "*"}${stripped}${anchoredEnd ? "" : "*"}`;
+}
+
+/**
+ * Translate the common subset of a `renovate.json` into treeupdt config:
+ * `ignoreDeps` and disabled package rules become `deny-packages`, rules that
+ * disable major updates become per-package `conservative` strategies.
+ * Everything else is reported as a warning rather than silently dropped.
+ */
+export function importRenovate(data: unknown): ImportResult {
+  const warnings: string[] = [];
+  const denyPackages: string[] = [];
+  const packages: Record<string, JsonValue> = {};
+
+  if (!isRecord(data)) {
+    return { config: {}, warnings: ["renovate config is not a JSON object"] };
+  }
+
+  denyPackages.push(...stringArray(data["ignoreDeps"]));
+
+  if (Array.isArray(data["schedule"])) {
+    warnings.push(
+      "schedule: Renovate's free-form windows don't translate; " +
+        "set a keyword or cron `schedule` in .treeupdt.json by hand",
+    );
+  }
+
+  const rules = Array.isArray(data["packageRules"]) ? data["packageRules"] : [];
+  for (const [i, rule] of rules.entries()) {
+    if (!isRecord(rule)) continue;
+    const names = stringArray(rule["matchPackageNames"]);
+    const globs = stringArray(rule["matchPackagePatterns"])
+      .map((pattern) => patternToGlob(pattern, warnings));
+    const matched = [...names, ...globs];
+    if (matched.length === 0) {
+      warnings.push(`packageRules[${i}]: no package matcher understood; skipped`);
+      continue;
+    }
+
+    const updateTypes = stringArray(rule["matchUpdateTypes"]);
+    if (rule["enabled"] === false) {
+      if (updateTypes.includes("major") && updateTypes.length === 1) {
+        for (const name of names) {
+          packages[name] = { strategy: "conservative" };
+        }
+        if (globs.length > 0) {
+          warnings.push(`packageRules[${i}]: per-pattern strategies are unsupported; dropped`);
+        }
+      } else {
+        denyPackages.push(...matched);
+      }
+      continue;
+    }
+    if (rule["groupName"] !== undefined) {
+      warnings.push(`packageRules[${i}]: grouping is unsupported; dropped`);
+      continue;
+    }
+    warnings.push(`packageRules[${i}]: rule has no treeupdt equivalent; skipped`);
+  }
+
+  const config: Record<string, JsonValue> = {};
+  if (denyPackages.length > 0) {
+    config["global"] = { "deny-packages": [...new Set(denyPackages)] };
+  }
+  if (Object.keys(packages).length > 0) {
+    config["packages"] = packages;
+  }
+  return { config, warnings };
+}
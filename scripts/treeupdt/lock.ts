@@ -0,0 +1,65 @@
+import { join } from "node:path";
+
+import { isRecord } from "../updater/assert.ts";
+
+export const lockFileName = ".treeupdt.lock";
+
+function describeHolder(contents: string): string {
+  try {
+    const parsed: unknown = JSON.parse(contents);
+    if (isRecord(parsed) && typeof parsed["pid"] === "number") {
+      const startedAt = typeof parsed["startedAt"] === "string"
+        ? ` since ${parsed["startedAt"]}`
+        : "";
+      return `pid ${parsed["pid"]}${startedAt}`;
+    }
+  } catch {
+    // Unreadable lock file; fall through to the generic description.
+  }
+  return "an unknown process";
+}
+
+/**
+ * Advisory lock file taken for the duration of an update run so concurrent
+ * invocations (e.g. CI and a human) fail fast instead of corrupting files.
+ */
+export class AdvisoryLock {
+  readonly path: string;
+
+  private constructor(path: string) {
+    this.path = path;
+  }
+
+  static async acquire(dir: string): Promise<AdvisoryLock> {
+    const path = join(dir, lockFileName);
+    const contents = `${
+      JSON.stringify({ pid: Deno.pid, startedAt: new Date().toISOString() })
+    }\n`;
+
+    try {
+      await Deno.writeTextFile(path, contents, { createNew: true });
+    } catch (err) {
+      if (!(err instanceof Deno.errors.AlreadyExists)) throw err;
+      const existing = await Deno.readTextFile(path).catch(() => "");
+      throw new Error(
+        `Another treeupdt instance (${describeHolder(existing)}) holds ${path}; ` +
+          `wait for it to finish or remove the lock file if it crashed`,
+      );
+    }
+
+    return new AdvisoryLock(path);
+  }
+
+  async release(): Promise<void> {
+    await Deno.remove(this.path).catch(() => undefined);
+  }
+}
+
+export async function withLock<T>(dir: string, fn: () => Promise<T>): Promise<T> {
+  const lock = await AdvisoryLock.acquire(dir);
+  try {
+    return await fn();
+  } finally {
+    await lock.release();
+  }
+}
@@ -0,0 +1,105 @@
+import { dirname, join } from "node:path";
+
+import { isRecord } from "../updater/assert.ts";
+import { fileExists } from "../updater/fs.ts";
+import type { FileType } from "./types.ts";
+
+/** Resolved dependency graph from a lockfile: package name -> direct deps. */
+export type Lockfile = ReadonlyMap<string, readonly string[]>;
+
+/** Parse `Cargo.lock` `[[package]]` blocks (name plus dependencies list). */
+export function parseCargoLock(content: string): Lockfile {
+  const graph = new Map<string, string[]>();
+  let name: string | null = null;
+  let deps: string[] = [];
+  let inDependencies = false;
+
+  const flush = () => {
+    if (name !== null) graph.set(name, deps);
+    name = null;
+    deps = [];
+  };
+
+  for (const rawLine of content.split("\n")) {
+    const line = rawLine.trim();
+    if (line === "[[package]]") {
+      flush();
+      inDependencies = false;
+      continue;
+    }
+    const nameMatch = line.match(/^name = "([^"]+)"$/);
+    if (nameMatch?.[1] !== undefined) {
+      name = nameMatch[1];
+      continue;
+    }
+    if (line.startsWith("dependencies = [")) {
+      inDependencies = !line.endsWith("]");
+      for (const dep of line.matchAll(/"([^"]+)"/g)) {
+        if (dep[1] !== undefined) deps.push(dep[1].split(" ")[0] ?? dep[1]);
+      }
+      continue;
+    }
+    if (inDependencies) {
+      if (line === "]") {
+        inDependencies = false;
+        continue;
+      }
+      const dep = line.match(/^"([^"]+)",?$/);
+      if (dep?.[1] !== undefined) deps.push(dep[1].split(" ")[0] ?? dep[1]);
+    }
+  }
+  flush();
+  return graph;
+}
+
+/** Parse npm `package-lock.json` (v2/v3 `packages` table). */
+export function parsePackageLock(content: string): Lockfile {
+  const parsed: unknown = JSON.parse(content);
+  const graph = new Map<string, string[]>();
+  if (!isRecord(parsed) || !isRecord(parsed["packages"])) return graph;
+
+  for (const [path, info] of Object.entries(parsed["packages"])) {
+    const marker = path.lastIndexOf("node_modules/");
+    if (marker === -1 || !isRecord(info)) continue;
+    const name = path.slice(marker + "node_modules/".length);
+    const deps = isRecord(info["dependencies"]) ? Object.keys(info["dependencies"]) : [];
+    graph.set(name, deps);
+  }
+  return graph;
+}
+
+/**
+ * Packages reachable from `name`'s dependency list — the set that can change
+ * versions when `name` is bumped and the lockfile is regenerated.
+ */
+export function transitiveDependencies(lock: Lockfile, name: string): string[] {
+  const seen = new Set<string>();
+  const queue = [...(lock.get(name) ?? [])];
+  while (queue.length > 0) {
+    const dep = queue.shift();
+    if (dep === undefined || seen.has(dep) || dep === name) continue;
+    seen.add(dep);
+    queue.push(...(lock.get(dep) ?? []));
+  }
+  return [...seen].sort();
+}
+
+/** The lockfile sitting next to a manifest, or null when there is none. */
+export async function findLockfile(
+  manifestPath: string,
+  fileType: FileType,
+): Promise<string | null> {
+  const names: Record<string, string> = {
+    cargo: "Cargo.lock",
+    npm: "package-lock.json",
+  };
+  const lockName = names[fileType];
+  if (lockName === undefined) return null;
+  const path = join(dirname(manifestPath), lockName);
+  return (await fileExists(path)) ? path : null;
+}
+
+/** Parse a lockfile by its file name convention. */
+export function parseLockfile(path: string, content: string): Lockfile {
+  return path.endsWith("Cargo.lock") ? parseCargoLock(content) : parsePackageLock(content);
+}
@@ -0,0 +1,67 @@
+/**
+ * Diagnostic logging shared by every command. Messages go to stderr so
+ * command output on stdout stays parseable; the CLI raises the level with
+ * `-v` / `-vv` and switches to one-JSON-object-per-line with
+ * `--log-format json` for CI.
+ */
+export const logLevels = ["error", "warn", "info", "debug", "trace"] as const;
+
+export type LogLevel = (typeof logLevels)[number];
+
+export const logFormats = ["text", "json"] as const;
+
+export type LogFormat = (typeof logFormats)[number];
+
+let currentLevel: LogLevel = "info";
+let currentFormat: LogFormat = "text";
+
+/** Map `-v` counts onto levels: 0 = info, 1 = debug, 2+ = trace. */
+export function configureLogging(verbosity: number, format: LogFormat): void {
+  currentLevel = verbosity >= 2 ? "trace" : verbosity === 1 ? "debug" : "info";
+  currentFormat = format;
+}
+
+function enabled(level: LogLevel): boolean {
+  return logLevels.indexOf(level) <= logLevels.indexOf(currentLevel);
+}
+
+function emit(
+  level: LogLevel,
+  message: string,
+  fields: Readonly<Record<string, unknown>>,
+): void {
+  if (!enabled(level)) return;
+  if (currentFormat === "json") {
+    console.error(JSON.stringify({
+      time: new Date().toISOString(),
+      level,
+      message,
+      ...fields,
+    }));
+    return;
+  }
+  const suffix = Object.entries(fields)
+    .map(([key, value]) => ` ${key}=${typeof value === "string" ? value : JSON.stringify(value)}`)
+    .join("");
+  console.error(`${level === "info" ? "" : `${level}: `}${message}${suffix}`);
+}
+
+export function error(message: string, fields: Readonly<Record<string, unknown>> = {}): void {
+  emit("error", message, fields);
+}
+
+export function warn(message: string, fields: Readonly<Record<string, unknown>> = {}): void {
+  emit("warn", message, fields);
+}
+
+export function info(message: string, fields: Readonly<Record<string, unknown>> = {}): void {
+  emit("info", message, fields);
+}
+
+export function debug(message: string, fields: Readonly<Record<string, unknown>> = {}): void {
+  emit("debug", message, fields);
+}
+
+export function trace(message: string, fields: Readonly<Record<string, unknown>> = {}): void {
+  emit("trace", message, fields);
+}
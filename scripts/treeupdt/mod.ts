@@ -0,0 +1,138 @@
+/**
+ * Public API of treeupdt, for tools that want to scan and check dependency
+ * trees programmatically (editors, bots, internal services) instead of going
+ * through the CLI. The typical flow:
+ *
+ * ```ts
+ * import { loadConfig, runCheckPipeline, scanTree } from "./mod.ts";
+ *
+ * const packages = await scanTree(".");
+ * const report = await runCheckPipeline(".");
+ * ```
+ *
+ * Scanners, sources, and updaters are all registry-based; pass your own
+ * registries to `scanTree` / `runCheckPipeline` to extend or replace the
+ * built-in ecosystems. Everything not re-exported here is internal and may
+ * change without notice; `cli.ts` is a thin front-end over this module.
+ *
+ * @module
+ */
+
+// Scanning: finding packages in a tree.
+export {
+  defaultScannerRegistry,
+  type Scanner,
+  ScannerRegistry,
+  scanTree,
+} from "./scan.ts";
+
+// Checking: querying sources and deciding what is updatable.
+export {
+  type CheckOptions,
+  defaultJobs,
+  defaultSourcePriority,
+  runCheckPipeline,
+} from "./check.ts";
+
+// Sources: where version lists come from.
+export {
+  CachedSource,
+  type ConditionalVersions,
+  defaultCacheTtlMs,
+  defaultSourceRegistry,
+  type RegistryOptions,
+  type Source,
+  type SourceOptions,
+  SourceRegistry,
+  UnknownPackageError,
+  type VersionInfo,
+} from "./sources.ts";
+
+// Plans: serializable descriptions of proposed edits (`plan` / `apply`).
+export {
+  buildPlan,
+  parsePlan,
+  type PlannedUpdate,
+  planVersion,
+  type UpdatePlan,
+} from "./plan.ts";
+
+// Updaters: applying a version bump to a manifest.
+export {
+  defaultUpdaterRegistry,
+  type UpdateOptions,
+  type Updater,
+  UpdaterRegistry,
+} from "./updaters.ts";
+
+// Journal of applied updates (`history`).
+export {
+  appendHistory,
+  historyFileName,
+  type HistoryEntry,
+  newTransactionId,
+  readHistory,
+} from "./history.ts";
+
+// Configuration: `.treeupdt.json` loading, layering, and lookups.
+export {
+  applyProfile,
+  type Config,
+  configFileName,
+  ConfigTree,
+  defaultConfig,
+  effectiveStrategy,
+  type GlobalConfig,
+  type GroupConfig,
+  lintConfig,
+  loadConfig,
+  mergeConfig,
+  type PackageConfig,
+  parseConfig,
+  type PluginConfig,
+  type SourceConfig,
+  type WebhookConfig,
+  type WebhookFormat,
+  webhookFormats,
+} from "./config.ts";
+
+// Webhook notifications (`check --notify`).
+export { newlyActionable, sendNotifications } from "./notify.ts";
+
+// Progress events from the check pipeline (`CheckOptions.onEvent`).
+export { type EventListener, nullListener, type TreeupdtEvent } from "./events.ts";
+
+// Failure classification with stable machine-readable codes.
+export { classifyError, type ErrorCode, errorCodes, TreeupdtError } from "./errors.ts";
+
+// WASM plugins: externally built scanners and sources.
+export { type LoadedPlugins, loadPlugins, WasmPlugin } from "./plugins.ts";
+
+// Response cache shared by the sources.
+export {
+  type Cache,
+  type CacheBackend,
+  type CacheEntry,
+  FileCache,
+  IndexedCache,
+  isFresh,
+  openCache,
+} from "./cache.ts";
+
+// Core data types shared across the layers above.
+export {
+  conventionalName,
+  type FileType,
+  knownFileTypes,
+  knownSourceTypes,
+  type Package,
+  type PackageId,
+  type SemverLevel,
+  type SourceHint,
+  type SourceSpan,
+  type SourceType,
+  type Strategy,
+  type UpdateEntry,
+  type UpdateOutcome,
+  type UpdateReport,
+} from "./types.ts";
@@ -0,0 +1,95 @@
+import type { WebhookConfig } from "./config.ts";
+import { fetchWithRetry } from "./http.ts";
+import { warn } from "./log.ts";
+import type { UpdateEntry } from "./types.ts";
+
+function updateKey(entry: UpdateEntry): string {
+  return `update ${entry.file} ${entry.name} ${entry.latest ?? ""}`;
+}
+
+function isWarning(entry: UpdateEntry): boolean {
+  return entry.eol === true || entry.currentVersionStatus === "yanked" ||
+    entry.currentVersionStatus === "deprecated";
+}
+
+function warningKey(entry: UpdateEntry): string {
+  return `warning ${entry.file} ${entry.name} ${entry.current}`;
+}
+
+/**
+ * Entries worth notifying about that the previous run did not already have:
+ * new available updates, plus packages whose current version newly became
+ * EOL, yanked, or deprecated. Null previous (first run) reports everything.
+ */
+export function newlyActionable(
+  entries: readonly UpdateEntry[],
+  previous: readonly UpdateEntry[] | null,
+): UpdateEntry[] {
+  const seen = new Set(
+    (previous ?? []).map((entry) =>
+      entry.updateAvailable === true ? updateKey(entry) : isWarning(entry) ? warningKey(entry) : ""
+    ),
+  );
+  return entries.filter((entry) =>
+    (entry.updateAvailable === true && !seen.has(updateKey(entry))) ||
+    (isWarning(entry) && !seen.has(warningKey(entry)))
+  );
+}
+
+function entryLine(entry: UpdateEntry): string {
+  if (entry.updateAvailable === true) {
+    const level = entry.semverLevel !== undefined ? ` (${entry.semverLevel})` : "";
+    return `${entry.name}: ${entry.current} -> ${entry.latest}${level} in ${entry.file}`;
+  }
+  if (entry.eol === true) {
+    return `${entry.name}: ${entry.current} is end-of-life in ${entry.file}`;
+  }
+  return `${entry.name}: ${entry.current} is ${entry.currentVersionStatus} in ${entry.file}`;
+}
+
+function summaryText(entries: readonly UpdateEntry[]): string {
+  const updates = entries.filter((entry) => entry.updateAvailable === true).length;
+  const warnings = entries.length - updates;
+  const header = `treeupdt: ${updates} new update${updates === 1 ? "" : "s"}` +
+    (warnings > 0 ? `, ${warnings} new warning${warnings === 1 ? "" : "s"}` : "");
+  return [header, ...entries.map(entryLine)].join("\n");
+}
+
+function payload(webhook: WebhookConfig, entries: readonly UpdateEntry[]): unknown {
+  const text = summaryText(entries);
+  switch (webhook.format) {
+    case "slack":
+      return { text };
+    case "discord":
+      return { content: text };
+    case "matrix":
+      return { msgtype: "m.text", body: text };
+    case "json":
+      return { source: "treeupdt", text, entries };
+  }
+}
+
+/**
+ * Post the summary to every configured webhook. Delivery failures are warned
+ * about rather than thrown: a dead webhook should not fail the check run.
+ */
+export async function sendNotifications(
+  webhooks: readonly WebhookConfig[],
+  entries: readonly UpdateEntry[],
+): Promise<void> {
+  for (const webhook of webhooks) {
+    try {
+      const res = await fetchWithRetry(webhook.url, {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(payload(webhook, entries)),
+      });
+      await res.body?.cancel().catch(() => undefined);
+      if (!res.ok) {
+        warn(`webhook ${webhook.url} answered HTTP ${res.status}`);
+      }
+    } catch (err) {
+      warn(`webhook ${webhook.url} failed: ${err instanceof Error ? err.message : err}`);
+    }
+  }
+}
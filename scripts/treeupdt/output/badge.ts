@@ -0,0 +1,73 @@
+import type { UpdateEntry } from "../types.ts";
+import { escapeHtml } from "./html.ts";
+
+export type BadgeData = Readonly<{
+  label: string;
+  message: string;
+  /** A shields.io named color. */
+  color: "brightgreen" | "yellow" | "red";
+}>;
+
+const colorValues: Record<BadgeData["color"], string> = {
+  brightgreen: "#4c1",
+  yellow: "#dfb317",
+  red: "#e05d44",
+};
+
+/** Summarize a check report as label/message/color for a badge. */
+export function badgeData(entries: readonly UpdateEntry[]): BadgeData {
+  const outdated = entries.filter((entry) => entry.updateAvailable === true);
+  if (outdated.length === 0) {
+    return { label: "dependencies", message: "up to date", color: "brightgreen" };
+  }
+  const hasMajor = outdated.some((entry) => entry.semverLevel === "major");
+  return {
+    label: "dependencies",
+    message: `${outdated.length} outdated`,
+    color: hasMajor ? "red" : "yellow",
+  };
+}
+
+/** The shape shields.io expects from a custom endpoint (schemaVersion 1). */
+export function shieldsJson(data: BadgeData): Record<string, unknown> {
+  return {
+    schemaVersion: 1,
+    label: data.label,
+    message: data.message,
+    color: data.color,
+  };
+}
+
+/** Rough text width in the 11px Verdana shields.io badges use. */
+function textWidth(text: string): number {
+  return Math.round(text.length * 6.5) + 10;
+}
+
+/** Flat-style SVG badge, visually matching shields.io's default. */
+export function renderBadgeSvg(data: BadgeData): string {
+  const labelWidth = textWidth(data.label);
+  const messageWidth = textWidth(data.message);
+  const width = labelWidth + messageWidth;
+  const label = escapeHtml(data.label);
+  const message = escapeHtml(data.message);
+  const color = colorValues[data.color];
+  return `<svg xmlns="http://www.w3.org/2000/svg" width="${width}" height="20" role="img" aria-label="${label}: ${message}">
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="${width}" height="20" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="${labelWidth}" height="20" fill="#555"/>
+    <rect x="${labelWidth}" width="${messageWidth}" height="20" fill="${color}"/>
+    <rect width="${width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="${labelWidth / 2}" y="14">${label}</text>
+    <text x="${labelWidth + messageWidth / 2}" y="14">${message}</text>
+  </g>
+</svg>
+`;
+}
@@ -0,0 +1,46 @@
+import type { UpdateEntry } from "../types.ts";
+
+/** Stable column order — append-only so downstream imports don't break. */
+export const csvColumns = [
+  "path",
+  "package",
+  "current",
+  "latest",
+  "stable",
+  "source",
+  "strategy",
+  "semver_level",
+  "releases_behind",
+  "current_published_at",
+] as const;
+
+function escapeField(text: string): string {
+  if (/[",\n]/.test(text)) {
+    return `"${text.replaceAll('"', '""')}"`;
+  }
+  return text;
+}
+
+/** CSV rendering of check results for spreadsheets and dashboards. */
+export function renderCsv(entries: readonly UpdateEntry[]): string {
+  const lines = [csvColumns.join(",")];
+  for (const entry of entries) {
+    const latest = entry.latest ?? "";
+    const row: Record<(typeof csvColumns)[number], string> = {
+      path: entry.file,
+      package: entry.name,
+      current: entry.current,
+      latest,
+      // Until prereleases are reported separately, the latest we surface is
+      // already the newest stable release.
+      stable: latest,
+      source: entry.source,
+      strategy: entry.strategy ?? "",
+      semver_level: entry.semverLevel ?? "",
+      releases_behind: entry.releasesBehind !== undefined ? String(entry.releasesBehind) : "",
+      current_published_at: entry.currentPublishedAt ?? "",
+    };
+    lines.push(csvColumns.map((column) => escapeField(row[column])).join(","));
+  }
+  return lines.join("\n");
+}
@@ -0,0 +1,90 @@
+import type { UpdateEntry } from "../types.ts";
+import { packageUrl } from "./markdown.ts";
+
+export function escapeHtml(text: string): string {
+  return text
+    .replaceAll("&", "&amp;")
+    .replaceAll("<", "&lt;")
+    .replaceAll(">", "&gt;")
+    .replaceAll('"', "&quot;");
+}
+
+const style = `
+  body { font-family: system-ui, sans-serif; margin: 2rem; }
+  table { border-collapse: collapse; width: 100%; }
+  th, td { border: 1px solid #ddd; padding: 0.4rem 0.7rem; text-align: left; }
+  th { background: #f5f5f5; cursor: pointer; }
+  tr.major td.level { color: #b00020; font-weight: bold; }
+  tr.minor td.level { color: #b36b00; }
+  tr.patch td.level { color: #2e7d32; }
+  .summary { margin-top: 1rem; color: #555; }
+`;
+
+const sortScript = `
+  document.querySelectorAll("th").forEach((th, col) => {
+    th.addEventListener("click", () => {
+      const tbody = th.closest("table").querySelector("tbody");
+      const rows = [...tbody.querySelectorAll("tr")];
+      const asc = th.dataset.asc !== "true";
+      th.dataset.asc = asc;
+      rows.sort((a, b) => {
+        const x = a.children[col].textContent;
+        const y = b.children[col].textContent;
+        return asc ? x.localeCompare(y) : y.localeCompare(x);
+      });
+      rows.forEach((row) => tbody.appendChild(row));
+    });
+  });
+`;
+
+/**
+ * Self-contained HTML report of check results: a sortable table with severity
+ * colors and registry links, for sharing with non-CLI stakeholders.
+ */
+export function renderHtml(entries: readonly UpdateEntry[]): string {
+  const updates = entries.filter((entry) => entry.updateAvailable === true);
+  const errors = entries.filter((entry) => entry.error !== undefined).length;
+
+  const rows = updates.map((entry) => {
+    const level = entry.semverLevel ?? "";
+    const url = entry.identifier !== undefined
+      ? packageUrl(entry.source, entry.identifier)
+      : null;
+    const sourceCell = url
+      ? `<a href="${escapeHtml(url)}">${escapeHtml(entry.source)}</a>`
+      : escapeHtml(entry.source);
+    return `      <tr class="${escapeHtml(level)}">` +
+      `<td>${escapeHtml(entry.name)}</td>` +
+      `<td>${escapeHtml(entry.file)}</td>` +
+      `<td>${escapeHtml(entry.current)}</td>` +
+      `<td>${escapeHtml(entry.latest ?? "")}</td>` +
+      `<td class="level">${escapeHtml(level) || "-"}</td>` +
+      `<td>${entry.releasesBehind ?? "-"}</td>` +
+      `<td>${sourceCell}</td></tr>`;
+  });
+
+  return `<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>treeupdt check report</title>
+  <style>${style}</style>
+</head>
+<body>
+  <h1>Dependency update report</h1>
+  <table>
+    <thead>
+      <tr><th>Package</th><th>File</th><th>Current</th><th>Latest</th><th>Level</th><th>Behind</th><th>Source</th></tr>
+    </thead>
+    <tbody>
+${rows.join("\n")}
+    </tbody>
+  </table>
+  <p class="summary">${entries.length} checked, ${updates.length} updates available, ${errors} errors. Generated ${
+    new Date().toISOString()
+  }.</p>
+  <script>${sortScript}</script>
+</body>
+</html>
+`;
+}
@@ -0,0 +1,44 @@
+import type { UpdateEntry } from "../types.ts";
+
+function escapeXml(text: string): string {
+  return text
+    .replaceAll("&", "&amp;")
+    .replaceAll("<", "&lt;")
+    .replaceAll(">", "&gt;")
+    .replaceAll('"', "&quot;");
+}
+
+/**
+ * JUnit XML rendering of check results: one test case per package, failing
+ * when an update is available and erroring when the check failed. CI systems
+ * that ingest test reports then show dependency freshness without extra glue.
+ */
+export function renderJunit(entries: readonly UpdateEntry[]): string {
+  const failures = entries.filter((entry) => entry.updateAvailable === true).length;
+  const errors = entries.filter((entry) => entry.error !== undefined).length;
+
+  const cases = entries.map((entry) => {
+    const name = escapeXml(entry.name);
+    const file = escapeXml(entry.file);
+    const open = `    <testcase name="${name}" classname="${file}">`;
+
+    if (entry.error !== undefined) {
+      return `${open}\n      <error message="${escapeXml(entry.error)}"/>\n    </testcase>`;
+    }
+    if (entry.updateAvailable === true) {
+      const message = `${entry.current} -> ${entry.latest ?? ""}` +
+        (entry.semverLevel !== undefined ? ` (${entry.semverLevel})` : "");
+      return `${open}\n      <failure message="${escapeXml(message)}">` +
+        `update available from ${escapeXml(entry.source)}</failure>\n    </testcase>`;
+    }
+    return `${open}</testcase>`;
+  });
+
+  return `<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="treeupdt" tests="${entries.length}" failures="${failures}" errors="${errors}">
+${cases.join("\n")}
+  </testsuite>
+</testsuites>
+`;
+}
@@ -0,0 +1,52 @@
+import type { UpdateEntry } from "../types.ts";
+
+/** Registry page for a package, used as the link column. */
+export function packageUrl(source: string, identifier: string): string | null {
+  switch (source) {
+    case "github":
+      return `https://github.com/${identifier}`;
+    case "npm":
+      return `https://www.npmjs.com/package/${identifier}`;
+    case "crates":
+      return `https://crates.io/crates/${identifier}`;
+    case "goproxy":
+      return `https://pkg.go.dev/${identifier}`;
+    default:
+      return null;
+  }
+}
+
+function escapeCell(text: string): string {
+  return text.replaceAll("|", "\\|");
+}
+
+/**
+ * GitHub-flavored Markdown table of available updates, suitable for pasting
+ * into PR descriptions or issue comments.
+ */
+export function renderMarkdown(entries: readonly UpdateEntry[]): string {
+  const updates = entries.filter((entry) => entry.updateAvailable === true);
+
+  const lines = [
+    "| Package | File | Current | Latest | Level | Behind | Link |",
+    "| --- | --- | --- | --- | --- | --- | --- |",
+  ];
+  for (const entry of updates) {
+    const url = entry.identifier !== undefined
+      ? packageUrl(entry.source, entry.identifier)
+      : null;
+    const link = url ? `[${entry.source}](${url})` : entry.source;
+    lines.push(
+      `| ${escapeCell(entry.name)} | ${escapeCell(entry.file)} | ` +
+        `${escapeCell(entry.current)} | ${escapeCell(entry.latest ?? "")} | ` +
+        `${entry.semverLevel ?? "-"} | ${entry.releasesBehind ?? "-"} | ${link} |`,
+    );
+  }
+
+  const errors = entries.filter((entry) => entry.error !== undefined).length;
+  lines.push("");
+  lines.push(
+    `${entries.length} checked, ${updates.length} updates available, ${errors} errors`,
+  );
+  return lines.join("\n");
+}
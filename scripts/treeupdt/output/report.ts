@@ -0,0 +1,128 @@
+import type { UpdateEntry } from "../types.ts";
+import { escapeHtml, renderHtml } from "./html.ts";
+import { renderMarkdown } from "./markdown.ts";
+
+/** Per-ecosystem freshness numbers for the report summary. */
+export type EcosystemStats = Readonly<{
+  fileType: string;
+  packages: number;
+  updates: number;
+  major: number;
+  minor: number;
+  patch: number;
+  errors: number;
+  eol: number;
+  /** Current versions reported deprecated or yanked upstream. */
+  unhealthy: number;
+}>;
+
+export function ecosystemStats(entries: readonly UpdateEntry[]): EcosystemStats[] {
+  const byType = new Map<string, UpdateEntry[]>();
+  for (const entry of entries) {
+    byType.set(entry.fileType, [...(byType.get(entry.fileType) ?? []), entry]);
+  }
+  return [...byType.keys()].sort().map((fileType) => {
+    const group = byType.get(fileType) ?? [];
+    const count = (predicate: (entry: UpdateEntry) => boolean): number =>
+      group.filter(predicate).length;
+    return {
+      fileType,
+      packages: group.length,
+      updates: count((e) => e.updateAvailable === true),
+      major: count((e) => e.updateAvailable === true && e.semverLevel === "major"),
+      minor: count((e) => e.updateAvailable === true && e.semverLevel === "minor"),
+      patch: count((e) => e.updateAvailable === true && e.semverLevel === "patch"),
+      errors: count((e) => e.error !== undefined),
+      eol: count((e) => e.eol === true),
+      unhealthy: count((e) =>
+        e.currentVersionStatus === "deprecated" || e.currentVersionStatus === "yanked"
+      ),
+    };
+  });
+}
+
+function problemLines(entries: readonly UpdateEntry[]): string[] {
+  const lines: string[] = [];
+  for (const entry of entries) {
+    if (entry.eol === true) {
+      lines.push(`${entry.name} (${entry.file}): end of life${
+        entry.eolDate !== undefined ? ` since ${entry.eolDate}` : ""
+      }`);
+    }
+    if (entry.currentVersionStatus === "yanked") {
+      lines.push(`${entry.name} (${entry.file}): current version ${entry.current} was yanked`);
+    }
+    if (entry.currentVersionStatus === "deprecated") {
+      lines.push(`${entry.name} (${entry.file}): current version ${entry.current} is deprecated`);
+    }
+    if (entry.error !== undefined) {
+      lines.push(`${entry.name} (${entry.file}): ${entry.error}`);
+    }
+  }
+  return lines;
+}
+
+/** Markdown freshness report: summary per ecosystem, updates, and problems. */
+export function renderReportMarkdown(entries: readonly UpdateEntry[]): string {
+  const stats = ecosystemStats(entries);
+  const lines = [
+    "# Dependency freshness report",
+    "",
+    `Generated ${new Date().toISOString()}.`,
+    "",
+    "## Summary",
+    "",
+    "| Ecosystem | Packages | Updates | Major | Minor | Patch | Errors | EOL | Unhealthy |",
+    "| --- | --- | --- | --- | --- | --- | --- | --- | --- |",
+    ...stats.map((s) =>
+      `| ${s.fileType} | ${s.packages} | ${s.updates} | ${s.major} | ${s.minor} | ` +
+      `${s.patch} | ${s.errors} | ${s.eol} | ${s.unhealthy} |`
+    ),
+    "",
+    "## Available updates",
+    "",
+    renderMarkdown(entries),
+  ];
+  const problems = problemLines(entries);
+  if (problems.length > 0) {
+    lines.push("", "## Problems", "", ...problems.map((line) => `- ${line}`));
+  }
+  return lines.join("\n");
+}
+
+/** HTML freshness report: the check table with a summary table prepended. */
+export function renderReportHtml(entries: readonly UpdateEntry[]): string {
+  const stats = ecosystemStats(entries);
+  const rows = stats.map((s) =>
+    `      <tr><td>${escapeHtml(s.fileType)}</td><td>${s.packages}</td>` +
+    `<td>${s.updates}</td><td>${s.major}</td><td>${s.minor}</td><td>${s.patch}</td>` +
+    `<td>${s.errors}</td><td>${s.eol}</td><td>${s.unhealthy}</td></tr>`
+  );
+  const summary = `  <h2>Summary</h2>
+  <table>
+    <thead>
+      <tr><th>Ecosystem</th><th>Packages</th><th>Updates</th><th>Major</th><th>Minor</th><th>Patch</th><th>Errors</th><th>EOL</th><th>Unhealthy</th></tr>
+    </thead>
+    <tbody>
+${rows.join("\n")}
+    </tbody>
+  </table>
+`;
+  // Splice the summary into the existing single-page report rather than
+  // maintaining a second HTML skeleton.
+  return renderHtml(entries).replace("<h1>Dependency update report</h1>\n", (match) =>
+    `${match}${summary}`);
+}
+
+/** JSON freshness report: summary statistics plus the raw entries. */
+export function renderReportJson(entries: readonly UpdateEntry[]): string {
+  return JSON.stringify(
+    {
+      generatedAt: new Date().toISOString(),
+      summary: ecosystemStats(entries),
+      entries,
+    },
+    null,
+    2,
+  );
+}
@@ -0,0 +1,106 @@
+import type { Package } from "../types.ts";
+import { packageUrl } from "./markdown.ts";
+
+/**
+ * Package URL (purl) for the package's primary source, when the ecosystem
+ * has a defined purl type. Hashes are left out of both formats: the scanners
+ * record declared versions, not resolved artifacts.
+ */
+function purl(pkg: Package): string | null {
+  const hint = pkg.sourceHints[0];
+  if (hint === undefined) return null;
+  switch (hint.source) {
+    case "npm":
+      return `pkg:npm/${hint.identifier}@${pkg.version}`;
+    case "crates":
+      return `pkg:cargo/${hint.identifier}@${pkg.version}`;
+    case "goproxy":
+      return `pkg:golang/${hint.identifier}@${pkg.version}`;
+    case "github":
+      return `pkg:github/${hint.identifier}@${pkg.version}`;
+    default:
+      return null;
+  }
+}
+
+function sourceUrl(pkg: Package): string | null {
+  const hint = pkg.sourceHints[0];
+  return hint === undefined ? null : packageUrl(hint.source, hint.identifier);
+}
+
+/** CycloneDX 1.5 JSON with one `library` component per scanned package. */
+export function renderCycloneDx(packages: readonly Package[]): string {
+  const components = packages.map((pkg) => {
+    const pkgPurl = purl(pkg);
+    const url = sourceUrl(pkg);
+    return {
+      type: "library",
+      name: pkg.name,
+      version: pkg.version,
+      ...(pkgPurl !== null ? { purl: pkgPurl, "bom-ref": pkgPurl } : {}),
+      ...(url !== null
+        ? { externalReferences: [{ type: "website", url }] }
+        : {}),
+      properties: [{ name: "treeupdt:file", value: pkg.file }],
+    };
+  });
+  return JSON.stringify(
+    {
+      bomFormat: "CycloneDX",
+      specVersion: "1.5",
+      version: 1,
+      metadata: {
+        timestamp: new Date().toISOString(),
+        tools: [{ name: "treeupdt" }],
+      },
+      components,
+    },
+    null,
+    2,
+  );
+}
+
+/** SPDX 2.3 JSON document; every package is DESCRIBED by the document. */
+export function renderSpdx(packages: readonly Package[], treeName: string): string {
+  const spdxPackages = packages.map((pkg, i) => {
+    const pkgPurl = purl(pkg);
+    const url = sourceUrl(pkg);
+    return {
+      SPDXID: `SPDXRef-Package-${i}`,
+      name: pkg.name,
+      versionInfo: pkg.version,
+      downloadLocation: url ?? "NOASSERTION",
+      ...(pkgPurl !== null
+        ? {
+          externalRefs: [{
+            referenceCategory: "PACKAGE-MANAGER",
+            referenceType: "purl",
+            referenceLocator: pkgPurl,
+          }],
+        }
+        : {}),
+      comment: `declared in ${pkg.file}`,
+    };
+  });
+  return JSON.stringify(
+    {
+      spdxVersion: "SPDX-2.3",
+      dataLicense: "CC0-1.0",
+      SPDXID: "SPDXRef-DOCUMENT",
+      name: treeName,
+      documentNamespace: `https://spdx.org/spdxdocs/treeupdt-${crypto.randomUUID()}`,
+      creationInfo: {
+        created: new Date().toISOString(),
+        creators: ["Tool: treeupdt"],
+      },
+      packages: spdxPackages,
+      relationships: spdxPackages.map((pkg) => ({
+        spdxElementId: "SPDXRef-DOCUMENT",
+        relationshipType: "DESCRIBES",
+        relatedSpdxElement: pkg.SPDXID,
+      })),
+    },
+    null,
+    2,
+  );
+}
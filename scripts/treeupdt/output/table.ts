@@ -0,0 +1,18 @@
+/**
+ * Left-aligned plain-text table with two-space gutters, for compact terminal
+ * views like `treeupdt outdated`. Column widths follow the widest cell.
+ */
+export function renderTable(
+  headers: readonly string[],
+  rows: readonly (readonly string[])[],
+): string {
+  const widths = headers.map((header, i) =>
+    Math.max(header.length, ...rows.map((row) => (row[i] ?? "").length))
+  );
+  const renderRow = (row: readonly string[]): string =>
+    row
+      .map((cell, i) => cell.padEnd(widths[i] ?? cell.length))
+      .join("  ")
+      .trimEnd();
+  return [renderRow(headers), ...rows.map(renderRow)].join("\n");
+}
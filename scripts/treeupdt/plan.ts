@@ -0,0 +1,76 @@
+import { isRecord } from "../updater/assert.ts";
+import type { UpdateEntry, UpdateReport } from "./types.ts";
+
+/** One proposed manifest edit, with the evidence behind it. */
+export type PlannedUpdate = Readonly<{
+  file: string;
+  package: string;
+  /** Identifier the updater matches in the manifest (source identifier or name). */
+  identifier: string;
+  fromVersion: string;
+  toVersion: string;
+  /** Source that reported the newer version. */
+  source: string;
+  fileType: string;
+}>;
+
+/** Version marker so `apply` rejects documents from other tools or eras. */
+export const planVersion = 1;
+
+/**
+ * Serializable description of every edit a run would make, produced by
+ * `treeupdt plan` and executed by `treeupdt apply`. Splitting the two lets
+ * CI gate on review of the plan and makes the applied set reproducible.
+ */
+export type UpdatePlan = Readonly<{
+  version: number;
+  createdAt: string;
+  root: string;
+  updates: readonly PlannedUpdate[];
+}>;
+
+function plannedUpdate(entry: UpdateEntry): PlannedUpdate | null {
+  if (entry.updateAvailable !== true || entry.latest === undefined) return null;
+  return {
+    file: entry.file,
+    package: entry.name,
+    identifier: entry.identifier ?? entry.name,
+    fromVersion: entry.current,
+    toVersion: entry.latest,
+    source: entry.source,
+    fileType: entry.fileType,
+  };
+}
+
+export function buildPlan(root: string, report: UpdateReport): UpdatePlan {
+  return {
+    version: planVersion,
+    createdAt: new Date().toISOString(),
+    root,
+    updates: report.entries.flatMap((entry) => {
+      const planned = plannedUpdate(entry);
+      return planned === null ? [] : [planned];
+    }),
+  };
+}
+
+export function parsePlan(data: unknown, context: string): UpdatePlan {
+  if (!isRecord(data) || data["version"] !== planVersion) {
+    throw new Error(`${context}: not a treeupdt plan (version ${planVersion})`);
+  }
+  const updates = data["updates"];
+  if (!Array.isArray(updates)) {
+    throw new Error(`${context}: expected an updates array`);
+  }
+  for (const [i, update] of updates.entries()) {
+    if (!isRecord(update)) {
+      throw new Error(`${context}.updates[${i}]: expected object`);
+    }
+    for (const key of ["file", "package", "identifier", "fromVersion", "toVersion"] as const) {
+      if (typeof update[key] !== "string") {
+        throw new Error(`${context}.updates[${i}].${key}: expected string`);
+      }
+    }
+  }
+  return data as UpdatePlan;
+}
@@ -0,0 +1,184 @@
+import { isRecord } from "../updater/assert.ts";
+import type { Config, PluginConfig } from "./config.ts";
+import { matchGlob } from "./glob.ts";
+import type { Scanner } from "./scan.ts";
+import type { Source, VersionInfo } from "./sources.ts";
+import { conventionalName, type Package, type SourceType } from "./types.ts";
+
+/**
+ * WASM plugin host, so site-specific file formats and internal registries can
+ * be supported without forking treeupdt. Modules are declared under `plugins`
+ * in the config and speak a small JSON-over-linear-memory contract:
+ *
+ * - export `memory` and `alloc(len) -> ptr` for passing input buffers in;
+ * - scanner plugins export `scan(ptr, len) -> ptr`, source plugins export
+ *   `list_versions(ptr, len) -> ptr`;
+ * - inputs and results are UTF-8 JSON; results are length-prefixed with a
+ *   little-endian u32 at the returned pointer.
+ *
+ * `scan` receives `{path, content}` and returns `[{name, version, section?,
+ * source?, identifier?}]`; `list_versions` receives `{identifier}` and
+ * returns `[{version, publishedAt?, prerelease?}]`.
+ */
+export class WasmPlugin {
+  readonly name: string;
+  readonly #exports: WebAssembly.Exports;
+  readonly #memory: WebAssembly.Memory;
+
+  private constructor(name: string, exports: WebAssembly.Exports, memory: WebAssembly.Memory) {
+    this.name = name;
+    this.#exports = exports;
+    this.#memory = memory;
+  }
+
+  static async load(name: string, path: string): Promise<WasmPlugin> {
+    let instance: WebAssembly.Instance;
+    try {
+      const module = await WebAssembly.compile(await Deno.readFile(path));
+      instance = await WebAssembly.instantiate(module, {});
+    } catch (err) {
+      throw new Error(
+        `plugin ${name}: failed to load ${path}: ${err instanceof Error ? err.message : err}`,
+      );
+    }
+    const memory = instance.exports["memory"];
+    if (!(memory instanceof WebAssembly.Memory)) {
+      throw new Error(`plugin ${name}: module does not export a memory`);
+    }
+    return new WasmPlugin(name, instance.exports, memory);
+  }
+
+  #fn(name: string): (...args: number[]) => number {
+    const fn = this.#exports[name];
+    if (typeof fn !== "function") {
+      throw new Error(`plugin ${this.name}: module does not export ${name}()`);
+    }
+    return fn as (...args: number[]) => number;
+  }
+
+  /** Call an exported function with a JSON payload, returning parsed JSON. */
+  call(fnName: string, input: unknown): unknown {
+    const bytes = new TextEncoder().encode(JSON.stringify(input));
+    const ptr = this.#fn("alloc")(bytes.length);
+    new Uint8Array(this.#memory.buffer, ptr, bytes.length).set(bytes);
+
+    const out = this.#fn(fnName)(ptr, bytes.length);
+    const length = new DataView(this.#memory.buffer).getUint32(out, true);
+    const text = new TextDecoder().decode(new Uint8Array(this.#memory.buffer, out + 4, length));
+    try {
+      return JSON.parse(text);
+    } catch {
+      throw new Error(`plugin ${this.name}: ${fnName}() returned invalid JSON`);
+    }
+  }
+}
+
+class WasmScanner implements Scanner {
+  readonly fileType: string;
+  readonly #plugin: WasmPlugin;
+  readonly #files: readonly string[];
+
+  constructor(plugin: WasmPlugin, fileType: string, files: readonly string[]) {
+    this.fileType = fileType;
+    this.#plugin = plugin;
+    this.#files = files;
+  }
+
+  matches(path: string): boolean {
+    return this.#files.some((pattern) => matchGlob(pattern, path));
+  }
+
+  scan(path: string, content: string): Package[] {
+    const result = this.#plugin.call("scan", { path, content });
+    if (!Array.isArray(result)) {
+      throw new Error(`plugin ${this.#plugin.name}: scan() must return an array`);
+    }
+    const packages: Package[] = [];
+    for (const item of result) {
+      if (
+        !isRecord(item) || typeof item["name"] !== "string" ||
+        typeof item["version"] !== "string"
+      ) {
+        throw new Error(`plugin ${this.#plugin.name}: scan() entries need name and version`);
+      }
+      const source = item["source"];
+      const identifier = item["identifier"];
+      const section = typeof item["section"] === "string" ? item["section"] : "";
+      const id = { file: path, section, name: item["name"] };
+      packages.push({
+        id,
+        name: conventionalName(id),
+        version: item["version"],
+        file: path,
+        fileType: this.fileType,
+        sourceHints: typeof source === "string"
+          ? [{ source, identifier: typeof identifier === "string" ? identifier : item["name"] }]
+          : [],
+      });
+    }
+    return packages;
+  }
+}
+
+class WasmSource implements Source {
+  readonly type: SourceType;
+  readonly #plugin: WasmPlugin;
+
+  constructor(plugin: WasmPlugin, type: SourceType) {
+    this.type = type;
+    this.#plugin = plugin;
+  }
+
+  listVersions(identifier: string): Promise<VersionInfo[]> {
+    const result = this.#plugin.call("list_versions", { identifier });
+    if (!Array.isArray(result)) {
+      throw new Error(`plugin ${this.#plugin.name}: list_versions() must return an array`);
+    }
+    const versions: VersionInfo[] = [];
+    for (const item of result) {
+      if (!isRecord(item) || typeof item["version"] !== "string") {
+        throw new Error(`plugin ${this.#plugin.name}: list_versions() entries need a version`);
+      }
+      versions.push({
+        version: item["version"],
+        ...(typeof item["publishedAt"] === "string" ? { publishedAt: item["publishedAt"] } : {}),
+        ...(item["prerelease"] === true ? { prerelease: true } : {}),
+      });
+    }
+    return Promise.resolve(versions);
+  }
+}
+
+export type LoadedPlugins = Readonly<{
+  scanners: readonly Scanner[];
+  sources: readonly Source[];
+}>;
+
+function pluginScanner(name: string, plugin: WasmPlugin, config: PluginConfig): Scanner {
+  if (config.fileType === undefined || config.files === undefined) {
+    throw new Error(`plugin ${name}: scanner plugins need file-type and files`);
+  }
+  return new WasmScanner(plugin, config.fileType, config.files);
+}
+
+function pluginSource(name: string, plugin: WasmPlugin, config: PluginConfig): Source {
+  if (config.sourceType === undefined) {
+    throw new Error(`plugin ${name}: source plugins need source-type`);
+  }
+  return new WasmSource(plugin, config.sourceType);
+}
+
+/** Load every plugin declared in the config; empty config loads nothing. */
+export async function loadPlugins(config: Config): Promise<LoadedPlugins> {
+  const scanners: Scanner[] = [];
+  const sources: Source[] = [];
+  for (const [name, pluginConfig] of Object.entries(config.plugins)) {
+    const plugin = await WasmPlugin.load(name, pluginConfig.path);
+    if (pluginConfig.kind === "scanner") {
+      scanners.push(pluginScanner(name, plugin, pluginConfig));
+    } else {
+      sources.push(pluginSource(name, plugin, pluginConfig));
+    }
+  }
+  return { scanners, sources };
+}
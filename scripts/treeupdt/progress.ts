@@ -0,0 +1,39 @@
+const encoder = new TextEncoder();
+
+/**
+ * Single-line progress indicator on stderr. Callers disable it for non-TTY
+ * runs and structured output so logs and JSON stay clean.
+ */
+export class Progress {
+  readonly #total: number;
+  readonly #enabled: boolean;
+  #done = 0;
+  #lastWidth = 0;
+
+  constructor(total: number, opts: Readonly<{ enabled?: boolean }> = {}) {
+    this.#total = total;
+    this.#enabled = opts.enabled ?? isStderrTerminal();
+  }
+
+  advance(label: string): void {
+    this.#done += 1;
+    if (!this.#enabled) return;
+    const line = `[${this.#done}/${this.#total}] ${label}`;
+    const padded = line.padEnd(this.#lastWidth);
+    this.#lastWidth = line.length;
+    Deno.stderr.writeSync(encoder.encode(`\r${padded}`));
+  }
+
+  finish(): void {
+    if (!this.#enabled || this.#lastWidth === 0) return;
+    Deno.stderr.writeSync(encoder.encode(`\r${" ".repeat(this.#lastWidth)}\r`));
+  }
+}
+
+export function isStderrTerminal(): boolean {
+  try {
+    return Deno.stderr.isTerminal();
+  } catch {
+    return false;
+  }
+}
@@ -0,0 +1,63 @@
+import type { VersionInfo } from "./sources.ts";
+
+const unitMs: Readonly<Record<string, number>> = {
+  s: 1000,
+  m: 60_000,
+  h: 3_600_000,
+  d: 86_400_000,
+};
+
+/** Parse a duration like `7d`, `12h`, `30m`, or `45s` into milliseconds. */
+export function parseDuration(text: string): number {
+  const match = text.trim().match(/^(\d+)([smhd])$/);
+  const amount = match?.[1];
+  const unit = match?.[2];
+  const factor = unit !== undefined ? unitMs[unit] : undefined;
+  if (!amount || factor === undefined) {
+    throw new Error(`Invalid duration: ${JSON.stringify(text)} (expected e.g. '7d', '12h')`);
+  }
+  return Number(amount) * factor;
+}
+
+/**
+ * Whether a version has been published for at least `minAgeMs`. Versions
+ * without a publish timestamp are treated as old enough, since most sources
+ * only expose timestamps for recent releases.
+ */
+export function isOldEnough(info: VersionInfo, minAgeMs: number, nowMs: number): boolean {
+  if (info.publishedAt === undefined) return true;
+  const publishedMs = Date.parse(info.publishedAt);
+  if (Number.isNaN(publishedMs)) return true;
+  return nowMs - publishedMs >= minAgeMs;
+}
+
+/** Drop versions younger than the cooldown, protecting against fresh releases. */
+export function filterByReleaseAge(
+  versions: readonly VersionInfo[],
+  minAgeMs: number,
+  nowMs: number = Date.now(),
+): VersionInfo[] {
+  return versions.filter((info) => isOldEnough(info, minAgeMs, nowMs));
+}
+
+/**
+ * Refuse an explicit update to a version younger than the cooldown. Versions
+ * the source doesn't know, or knows without a timestamp, pass -- the same
+ * bias as `isOldEnough`.
+ */
+export function assertReleaseOldEnough(
+  versions: readonly VersionInfo[],
+  version: string,
+  minimumReleaseAge: string,
+): void {
+  const minAgeMs = parseDuration(minimumReleaseAge);
+  const info = versions.find(
+    (v) => v.version === version || v.version === version.replace(/^v/, ""),
+  );
+  if (info !== undefined && !isOldEnough(info, minAgeMs, Date.now())) {
+    throw new Error(
+      `${version} was published at ${info.publishedAt}, ` +
+        `younger than the configured minimum-release-age of ${minimumReleaseAge}`,
+    );
+  }
+}
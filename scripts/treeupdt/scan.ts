@@ -0,0 +1,116 @@
+import { join, relative } from "node:path";
+
+import { fileExists } from "../updater/fs.ts";
+import { matchGlob } from "./glob.ts";
+import { ignoreFileName, type IgnoreRule, isIgnored, parseIgnoreRules } from "./ignore.ts";
+import { CargoScanner } from "./scanners/cargo.ts";
+import { GoScanner } from "./scanners/go.ts";
+import { NpmScanner } from "./scanners/npm.ts";
+import type { FileType, Package } from "./types.ts";
+
+export interface Scanner {
+  readonly fileType: FileType;
+  /** Whether this scanner handles the given manifest path. */
+  matches(path: string): boolean;
+  /** May be async: lockfile- or network-assisted scanners do their own IO. */
+  scan(path: string, content: string): Package[] | Promise<Package[]>;
+}
+
+export class ScannerRegistry {
+  readonly #scanners = new Map<FileType, Scanner>();
+
+  /** Chainable, so external scanners can be stacked onto the defaults. */
+  register(scanner: Scanner): this {
+    this.#scanners.set(scanner.fileType, scanner);
+    return this;
+  }
+
+  get scanners(): Scanner[] {
+    return [...this.#scanners.values()];
+  }
+
+  forFile(path: string): Scanner | null {
+    for (const scanner of this.#scanners.values()) {
+      if (scanner.matches(path)) return scanner;
+    }
+    return null;
+  }
+}
+
+export function defaultScannerRegistry(): ScannerRegistry {
+  const registry = new ScannerRegistry();
+  registry.register(new CargoScanner());
+  registry.register(new GoScanner());
+  registry.register(new NpmScanner());
+  return registry;
+}
+
+const skippedDirs = new Set([".git", "node_modules", "target", "result", ".direnv"]);
+
+type IgnoreLayer = readonly [string, readonly IgnoreRule[]];
+
+async function collectFiles(
+  root: string,
+  dir: string,
+  layers: readonly IgnoreLayer[],
+  out: string[],
+): Promise<void> {
+  // `.treeupdtignore` files apply to their own directory and everything below.
+  const ignorePath = join(dir, ignoreFileName);
+  if (await fileExists(ignorePath)) {
+    layers = [...layers, [dir, parseIgnoreRules(await Deno.readTextFile(ignorePath))]];
+  }
+
+  for await (const entry of Deno.readDir(dir)) {
+    const path = join(dir, entry.name);
+    if (isIgnored(layers, (base) => relative(base, path), entry.isDirectory)) {
+      continue;
+    }
+    if (entry.isDirectory) {
+      if (!skippedDirs.has(entry.name)) {
+        await collectFiles(root, path, layers, out);
+      }
+      continue;
+    }
+    if (entry.isFile) {
+      out.push(relative(root, path));
+    }
+  }
+}
+
+/** All files under a root, honoring `.treeupdtignore` and skipped dirs. */
+export async function listTreeFiles(root: string): Promise<string[]> {
+  const files: string[] = [];
+  await collectFiles(root, root, [], files);
+  files.sort();
+  return files;
+}
+
+/** Walk a tree and run every matching scanner over its manifests. */
+export async function scanTree(
+  root: string,
+  registry: ScannerRegistry = defaultScannerRegistry(),
+  excludes: readonly string[] = [],
+): Promise<Package[]> {
+  const files = await listTreeFiles(root);
+
+  const manifests = files
+    .filter((file) => !excludes.some((pattern) => matchGlob(pattern, file)))
+    .flatMap((file) => {
+      const scanner = registry.forFile(file);
+      return scanner === null ? [] : [{ file, scanner }];
+    });
+
+  // Manifest reads run concurrently; the scan calls stay in sorted order
+  // because some scanners carry state across files (the cargo scanner must
+  // see a workspace root before the members inheriting its versions).
+  const contents = await Promise.all(
+    manifests.map(({ file }) => Deno.readTextFile(join(root, file))),
+  );
+
+  const packages: Package[] = [];
+  for (const [i, { file, scanner }] of manifests.entries()) {
+    packages.push(...await scanner.scan(file, contents[i] ?? ""));
+  }
+  return packages;
+}
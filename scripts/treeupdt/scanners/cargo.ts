@@ -0,0 +1,141 @@
+import { basename, dirname } from "node:path";
+
+import { annotationFromLine } from "../annotations.ts";
+import type { Scanner } from "../scan.ts";
+import { quotedSpanInLine } from "../span.ts";
+import { type Annotation, conventionalName, type Package } from "../types.ts";
+
+const dependencySections = new Set([
+  "dependencies",
+  "dev-dependencies",
+  "build-dependencies",
+  "workspace.dependencies",
+]);
+
+export type CargoDependency = Readonly<{
+  name: string;
+  section: string;
+  /** Declared version requirement, or null for path/git-only dependencies. */
+  version: string | null;
+  /** True for `{ workspace = true }` entries inheriting the workspace version. */
+  workspace: boolean;
+  /** Inline `# treeupdt: ...` directive on the entry's line, if any. */
+  annotation: Annotation | null;
+  /** 1-based line the entry appears on. */
+  line: number;
+}>;
+
+export function parseCargoDependencies(content: string): CargoDependency[] {
+  const deps: CargoDependency[] = [];
+  let section = "";
+
+  for (const [index, line] of content.split("\n").entries()) {
+    const trimmed = line.trim();
+    if (!trimmed || trimmed.startsWith("#")) continue;
+
+    const sectionMatch = trimmed.match(/^\[([^\]]+)\]$/);
+    if (sectionMatch?.[1]) {
+      section = sectionMatch[1];
+      continue;
+    }
+    if (!dependencySections.has(section)) continue;
+
+    const entryMatch = trimmed.match(/^([A-Za-z0-9_-]+)\s*=\s*(.+)$/);
+    if (!entryMatch?.[1] || !entryMatch[2]) continue;
+    const [, name, value] = entryMatch;
+    const annotation = annotationFromLine(trimmed);
+
+    if (value.startsWith('"')) {
+      const version = value.match(/^"([^"]+)"/)?.[1] ?? null;
+      deps.push({
+        name: name ?? "",
+        section,
+        version,
+        workspace: false,
+        annotation,
+        line: index + 1,
+      });
+      continue;
+    }
+
+    if (value.startsWith("{")) {
+      const workspace = /\bworkspace\s*=\s*true\b/.test(value);
+      const version = value.match(/\bversion\s*=\s*"([^"]+)"/)?.[1] ?? null;
+      deps.push({ name: name ?? "", section, version, workspace, annotation, line: index + 1 });
+    }
+  }
+  return deps;
+}
+
+export class CargoScanner implements Scanner {
+  readonly fileType = "cargo" as const;
+
+  /** `[workspace.dependencies]` versions per workspace root directory. */
+  readonly #workspaceVersions = new Map<string, Map<string, string>>();
+
+  matches(path: string): boolean {
+    return basename(path) === "Cargo.toml";
+  }
+
+  #resolveWorkspaceVersion(file: string, name: string): string | null {
+    let dir = dirname(file);
+    while (true) {
+      const versions = this.#workspaceVersions.get(dir);
+      const version = versions?.get(name);
+      if (version !== undefined) return version;
+      const parent = dirname(dir);
+      if (parent === dir) return null;
+      dir = parent;
+    }
+  }
+
+  scan(path: string, content: string): Package[] {
+    const deps = parseCargoDependencies(content);
+    const lines = content.split("\n");
+    const lineStarts: number[] = [];
+    let offset = 0;
+    for (const line of lines) {
+      lineStarts.push(offset);
+      offset += line.length + 1;
+    }
+
+    const workspaceDeps = deps.filter((d) => d.section === "workspace.dependencies");
+    if (workspaceDeps.length > 0) {
+      const versions = new Map<string, string>();
+      for (const dep of workspaceDeps) {
+        if (dep.version !== null) versions.set(dep.name, dep.version);
+      }
+      this.#workspaceVersions.set(dirname(path), versions);
+    }
+
+    const packages: Package[] = [];
+    for (const dep of deps) {
+      if (dep.section === "workspace.dependencies") continue;
+      const version = dep.workspace
+        ? this.#resolveWorkspaceVersion(path, dep.name)
+        : dep.version;
+      if (version === null) continue;
+      // A workspace-inherited version literal lives in the root manifest, not here.
+      const span = dep.workspace
+        ? null
+        : quotedSpanInLine(
+          lines[dep.line - 1] ?? "",
+          dep.line,
+          lineStarts[dep.line - 1] ?? 0,
+          version,
+        );
+      const id = { file: path, section: dep.section, name: dep.name };
+      packages.push({
+        id,
+        name: conventionalName(id),
+        version,
+        file: path,
+        fileType: "cargo",
+        sourceHints: [{ source: "crates", identifier: dep.name }],
+        ...(dep.annotation !== null ? { annotation: dep.annotation } : {}),
+        ...(span !== null ? { span } : {}),
+      });
+    }
+    return packages;
+  }
+}
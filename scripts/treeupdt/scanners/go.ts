@@ -0,0 +1,77 @@
+import { basename } from "node:path";
+
+import { annotationFromLine } from "../annotations.ts";
+import type { Scanner } from "../scan.ts";
+import { spanInLine } from "../span.ts";
+import { conventionalName, type Package } from "../types.ts";
+
+export class GoScanner implements Scanner {
+  readonly fileType = "go" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "go.mod";
+  }
+
+  scan(path: string, content: string): Package[] {
+    const packages: Package[] = [];
+    let inRequireBlock = false;
+    let lineNumber = 0;
+    let offset = 0;
+
+    for (const line of content.split("\n")) {
+      lineNumber += 1;
+      const lineStart = offset;
+      offset += line.length + 1;
+      const trimmed = line.trim();
+      const goDirective = trimmed.match(/^go\s+(\d+\.\d+(?:\.\d+)?)$/);
+      if (goDirective?.[1]) {
+        const span = spanInLine(line, lineNumber, lineStart, goDirective[1]);
+        const id = { file: path, section: "runtime", name: "go" };
+        packages.push({
+          id,
+          name: conventionalName(id),
+          version: goDirective[1],
+          file: path,
+          fileType: "go",
+          sourceHints: [],
+          eolProduct: "go",
+          ...(span !== null ? { span } : {}),
+        });
+        continue;
+      }
+      if (trimmed === "require (") {
+        inRequireBlock = true;
+        continue;
+      }
+      if (inRequireBlock && trimmed === ")") {
+        inRequireBlock = false;
+        continue;
+      }
+
+      const text = inRequireBlock
+        ? trimmed
+        : trimmed.startsWith("require ")
+        ? trimmed.slice("require ".length)
+        : null;
+      if (text === null) continue;
+
+      const match = text.match(/^(\S+)\s+(v\S+)/);
+      if (!match?.[1] || !match[2]) continue;
+
+      const annotation = annotationFromLine(trimmed);
+      const span = spanInLine(line, lineNumber, lineStart, match[2]);
+      const id = { file: path, section: "require", name: match[1] };
+      packages.push({
+        id,
+        name: conventionalName(id),
+        version: match[2],
+        file: path,
+        fileType: "go",
+        sourceHints: [{ source: "goproxy", identifier: match[1] }],
+        ...(annotation !== null ? { annotation } : {}),
+        ...(span !== null ? { span } : {}),
+      });
+    }
+    return packages;
+  }
+}
@@ -0,0 +1,91 @@
+import { basename } from "node:path";
+
+import { isRecord } from "../../updater/assert.ts";
+import { warn } from "../log.ts";
+import type { Scanner } from "../scan.ts";
+import { findQuotedSpan } from "../span.ts";
+import { conventionalName, type Package } from "../types.ts";
+
+/** package.json sections scanned for dependency entries, with name prefixes. */
+export const npmSectionPrefixes: Readonly<Record<string, string>> = {
+  dependencies: "dependencies",
+  devDependencies: "dev-dependencies",
+  peerDependencies: "peer-dependencies",
+  optionalDependencies: "optional-dependencies",
+  overrides: "overrides",
+  resolutions: "resolutions",
+};
+
+/** Specifiers that don't name a registry version (local paths, git URLs...). */
+function isRegistryRange(range: string): boolean {
+  return !/^(file:|link:|git\+|git:|github:|https?:)/.test(range);
+}
+
+export class NpmScanner implements Scanner {
+  readonly fileType = "npm" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "package.json";
+  }
+
+  scan(path: string, content: string): Package[] {
+    // One malformed package.json (a fixture, a template) must not abort the
+    // whole scan; skip the file with a warning that names it.
+    let parsed: unknown;
+    try {
+      parsed = JSON.parse(content);
+    } catch (err) {
+      warn("skipping unparseable package.json", {
+        file: path,
+        error: err instanceof Error ? err.message : String(err),
+      });
+      return [];
+    }
+    if (!isRecord(parsed)) return [];
+
+    const packages: Package[] = [];
+
+    // JSON.parse drops positions, so spans come from a literal search over the
+    // raw content, anchored on the section and entry keys to stay unambiguous.
+    const engines = parsed["engines"];
+    if (isRecord(engines) && typeof engines["node"] === "string") {
+      const keyIndex = content.indexOf('"node"', content.indexOf('"engines"'));
+      const span = keyIndex === -1 ? null : findQuotedSpan(content, engines["node"], keyIndex + 1);
+      const id = { file: path, section: "engines", name: "node" };
+      packages.push({
+        id,
+        name: conventionalName(id),
+        version: engines["node"],
+        file: path,
+        fileType: "npm",
+        sourceHints: [],
+        eolProduct: "nodejs",
+        ...(span !== null ? { span } : {}),
+      });
+    }
+
+    for (const [section, prefix] of Object.entries(npmSectionPrefixes)) {
+      const deps = parsed[section];
+      if (!isRecord(deps)) continue;
+      const sectionIndex = content.indexOf(`"${section}"`);
+
+      for (const [name, range] of Object.entries(deps)) {
+        // `overrides` values can be nested objects; only string entries name a version.
+        if (typeof range !== "string" || !isRegistryRange(range)) continue;
+        const keyIndex = content.indexOf(`"${name}"`, sectionIndex + 1);
+        const span = keyIndex === -1 ? null : findQuotedSpan(content, range, keyIndex + 1);
+        const id = { file: path, section: prefix, name };
+        packages.push({
+          id,
+          name: conventionalName(id),
+          version: range,
+          file: path,
+          fileType: "npm",
+          sourceHints: [{ source: "npm", identifier: name }],
+          ...(span !== null ? { span } : {}),
+        });
+      }
+    }
+    return packages;
+  }
+}
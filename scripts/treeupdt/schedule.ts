@@ -0,0 +1,76 @@
+/**
+ * Update cadence windows. A schedule is either a keyword (`daily`, `weekly`,
+ * `monthly`, `quarterly`) or a five-field cron expression whose day-of-month,
+ * month, and day-of-week fields decide whether today falls inside the window;
+ * the minute and hour fields are ignored because checks run at most daily.
+ */
+
+export const scheduleKeywords = ["daily", "weekly", "monthly", "quarterly"] as const;
+
+/** Does one cron field (`*`, lists, ranges, steps) cover `value`? */
+function cronFieldMatches(field: string, value: number): boolean {
+  for (const part of field.split(",")) {
+    const [range = "", step] = part.split("/");
+    const every = step !== undefined ? Number(step) : 1;
+    if (!Number.isInteger(every) || every < 1) {
+      throw new Error(`invalid cron step: ${part}`);
+    }
+
+    let lo: number;
+    let hi: number;
+    if (range === "*") {
+      lo = 0;
+      hi = 99;
+    } else if (range.includes("-")) {
+      const [a, b] = range.split("-").map(Number);
+      if (a === undefined || b === undefined || !Number.isInteger(a) || !Number.isInteger(b)) {
+        throw new Error(`invalid cron range: ${part}`);
+      }
+      lo = a;
+      hi = b;
+    } else {
+      const single = Number(range);
+      if (!Number.isInteger(single)) {
+        throw new Error(`invalid cron field: ${part}`);
+      }
+      lo = single;
+      hi = single;
+    }
+    if (value >= lo && value <= hi && (value - lo) % every === 0) return true;
+  }
+  return false;
+}
+
+/**
+ * True when the schedule's window is open at `now`. Keywords follow Renovate
+ * conventions: `daily` is always open, `weekly` opens on Mondays, `monthly`
+ * on the first of the month, `quarterly` on the first of each quarter.
+ */
+export function scheduleOpen(spec: string, now = new Date()): boolean {
+  switch (spec) {
+    case "daily":
+      return true;
+    case "weekly":
+      return now.getDay() === 1;
+    case "monthly":
+      return now.getDate() === 1;
+    case "quarterly":
+      return now.getDate() === 1 && now.getMonth() % 3 === 0;
+  }
+
+  const fields = spec.trim().split(/\s+/);
+  if (fields.length !== 5) {
+    throw new Error(
+      `invalid schedule: ${spec} (expected ${scheduleKeywords.join(", ")} or a cron expression)`,
+    );
+  }
+  const [, , dayOfMonth, month, dayOfWeek] = fields;
+  return cronFieldMatches(dayOfMonth ?? "*", now.getDate()) &&
+    cronFieldMatches(month ?? "*", now.getMonth() + 1) &&
+    cronFieldMatches(dayOfWeek ?? "*", now.getDay());
+}
+
+/** Throws with a descriptive message when `spec` is not a valid schedule. */
+export function validateSchedule(spec: string): void {
+  scheduleOpen(spec);
+}
@@ -0,0 +1,58 @@
+import { matchGlob } from "./glob.ts";
+import type { Package } from "./types.ts";
+
+/**
+ * A positional selector for `scan`/`check`: a file path, a directory, a glob,
+ * optionally narrowed to one package with `path:package-name`. The package
+ * part accepts the flat display name (`dependencies-react`), the bare name
+ * (`react`), or the structured `section/name` form (`dependencies/react`).
+ */
+export type PathSpec = Readonly<{
+  pattern: string;
+  packageName: string | null;
+}>;
+
+export function parsePathSpec(spec: string): PathSpec {
+  const colon = spec.indexOf(":");
+  if (colon === -1) {
+    return { pattern: spec, packageName: null };
+  }
+  return {
+    pattern: spec.slice(0, colon),
+    packageName: spec.slice(colon + 1),
+  };
+}
+
+function normalize(path: string): string {
+  return path.replace(/^\.\//, "").replace(/\/+$/, "");
+}
+
+/** Whether a scanned file path falls under a spec's file/directory/glob pattern. */
+export function matchesPattern(pattern: string, file: string): boolean {
+  const cleaned = normalize(pattern);
+  if (cleaned === "" || cleaned === ".") return true;
+  if (/[*?[{]/.test(cleaned)) {
+    return matchGlob(cleaned, file);
+  }
+  return file === cleaned || file.startsWith(`${cleaned}/`);
+}
+
+/** Whether a spec's package part names the package, in any accepted form. */
+export function matchesPackageName(name: string, pkg: Package): boolean {
+  return name === pkg.name || name === pkg.id.name ||
+    name === `${pkg.id.section}/${pkg.id.name}`;
+}
+
+/** Keep packages matching any spec; no specs means keep everything. */
+export function selectPackages(
+  packages: readonly Package[],
+  specs: readonly PathSpec[],
+): Package[] {
+  if (specs.length === 0) return [...packages];
+  return packages.filter((pkg) =>
+    specs.some((spec) =>
+      matchesPattern(spec.pattern, pkg.file) &&
+      (spec.packageName === null || matchesPackageName(spec.packageName, pkg))
+    )
+  );
+}
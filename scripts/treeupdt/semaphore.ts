@@ -0,0 +1,38 @@
+/** Counting semaphore used to cap per-source concurrency during checks. */
+export class Semaphore {
+  #available: number;
+  readonly #waiters: Array<() => void> = [];
+
+  constructor(permits: number) {
+    if (!Number.isInteger(permits) || permits < 1) {
+      throw new Error(`Semaphore: invalid permit count: ${String(permits)}`);
+    }
+    this.#available = permits;
+  }
+
+  async acquire(): Promise<void> {
+    if (this.#available > 0) {
+      this.#available -= 1;
+      return;
+    }
+    await new Promise<void>((resolve) => this.#waiters.push(resolve));
+  }
+
+  release(): void {
+    const next = this.#waiters.shift();
+    if (next) {
+      next();
+      return;
+    }
+    this.#available += 1;
+  }
+
+  async with<T>(fn: () => Promise<T>): Promise<T> {
+    await this.acquire();
+    try {
+      return await fn();
+    } finally {
+      this.release();
+    }
+  }
+}
@@ -0,0 +1,129 @@
+import type { SemverLevel } from "./types.ts";
+
+export type Semver = Readonly<{
+  major: number;
+  minor: number;
+  patch: number;
+  prerelease: string;
+}>;
+
+export function parseSemver(version: string): Semver | null {
+  const match = version.trim().match(/^v?(\d+)(?:\.(\d+))?(?:\.(\d+))?(?:[-+](.+))?$/);
+  if (!match?.[1]) return null;
+  return {
+    major: Number(match[1]),
+    minor: Number(match[2] ?? 0),
+    patch: Number(match[3] ?? 0),
+    prerelease: match[4] ?? "",
+  };
+}
+
+export function compareSemver(a: Semver, b: Semver): number {
+  if (a.major !== b.major) return a.major < b.major ? -1 : 1;
+  if (a.minor !== b.minor) return a.minor < b.minor ? -1 : 1;
+  if (a.patch !== b.patch) return a.patch < b.patch ? -1 : 1;
+  if (a.prerelease === b.prerelease) return 0;
+  if (!a.prerelease) return 1;
+  if (!b.prerelease) return -1;
+  return a.prerelease < b.prerelease ? -1 : 1;
+}
+
+export type { SemverLevel };
+
+/** Parse a version that may carry range syntax, e.g. `^4.18.0` or `>=1.2`. */
+export function looseParseSemver(text: string): Semver | null {
+  return parseSemver(text.trim().replace(/^[\^~=<>\s]+/, ""));
+}
+
+/**
+ * Whether `latest` is actually newer than `current`: `1.0` equals `1.0.0`,
+ * range prefixes like `^` are ignored, and a current version ahead of latest
+ * is not an update. Falls back to an ordered numeric string comparison when
+ * either side isn't semver.
+ */
+export function isNewerVersion(current: string, latest: string): boolean {
+  const from = looseParseSemver(current);
+  const to = looseParseSemver(latest);
+  if (from && to) return compareSemver(to, from) > 0;
+  return latest !== current &&
+    latest.localeCompare(current, undefined, { numeric: true }) > 0;
+}
+
+/** Classify current -> latest as a major, minor, or patch change. */
+export function classifyChange(current: string, latest: string): SemverLevel | null {
+  const from = looseParseSemver(current);
+  const to = looseParseSemver(latest);
+  if (!from || !to) return null;
+  if (from.major !== to.major) return "major";
+  if (from.minor !== to.minor) return "minor";
+  if (from.patch !== to.patch || from.prerelease !== to.prerelease) return "patch";
+  return null;
+}
+
+/** Render with the same number of components as `template` (`1.2` stays two-part). */
+function formatLikeTemplate(version: Semver, template: string): string {
+  const parts = template.split(".").length;
+  if (parts <= 1) return String(version.major);
+  if (parts === 2) return `${version.major}.${version.minor}`;
+  const prerelease = version.prerelease ? `-${version.prerelease}` : "";
+  return `${version.major}.${version.minor}.${version.patch}${prerelease}`;
+}
+
+function rewriteComparator(comparator: string, target: Semver): string {
+  const match = comparator.match(/^(\^|~|>=|<=|>|<|=)?\s*(.+)$/);
+  const op = match?.[1] ?? "";
+  const versionText = match?.[2] ?? comparator;
+  const current = parseSemver(versionText.replaceAll(/[xX*]/g, "0"));
+  if (!current) return comparator;
+
+  if (op === "<" || op === "<=") {
+    // Upper bound: only raise it when the target version no longer fits.
+    if (compareSemver(target, current) < 0) return comparator;
+    return `<${target.major + 1}`;
+  }
+
+  if (/[xX*]/.test(versionText)) {
+    // Wildcard like `1.x` or `1.2.x`: substitute the fixed components.
+    return op + versionText
+      .split(".")
+      .map((part, i) => {
+        if (/[xX*]/.test(part)) return part;
+        if (i === 0) return String(target.major);
+        if (i === 1) return String(target.minor);
+        return String(target.patch);
+      })
+      .join(".");
+  }
+
+  return op + formatLikeTemplate(target, versionText);
+}
+
+/**
+ * Rewrite a node-semver range to admit `newVersion` while preserving its
+ * style: `^`/`~`/comparator prefixes, multi-comparator ranges like
+ * `>=1.2 <2`, wildcards like `1.x`, `*`, and the `workspace:` protocol.
+ */
+export function rewriteRange(range: string, newVersion: string): string {
+  const target = parseSemver(newVersion);
+  if (!target) return newVersion;
+
+  const trimmed = range.trim();
+  if (trimmed === "" || trimmed === "*" || /^[xX]$/.test(trimmed)) return range;
+
+  if (trimmed.startsWith("workspace:")) {
+    const inner = trimmed.slice("workspace:".length);
+    if (inner === "" || inner === "*" || inner === "^" || inner === "~") return range;
+    return `workspace:${rewriteRange(inner, newVersion)}`;
+  }
+
+  if (trimmed.includes("||")) {
+    // Alternation: extend with the new version's caret range rather than
+    // guessing which branch to rewrite.
+    return `${trimmed} || ^${formatLikeTemplate(target, "0.0.0")}`;
+  }
+
+  return trimmed
+    .split(/\s+/)
+    .map((comparator) => rewriteComparator(comparator, target))
+    .join(" ");
+}
@@ -0,0 +1,264 @@
+import { isRecord } from "../updater/assert.ts";
+import { type Cache, type CacheEntry, isFresh, openCache, recordCacheAccess } from "./cache.ts";
+import { type Config, defaultConfig, effectiveCacheTtlMs, resolveSourceToken } from "./config.ts";
+import { TreeupdtError } from "./errors.ts";
+import { HttpStatusError, type Validators } from "./http.ts";
+import { debug, warn } from "./log.ts";
+import { CratesSource } from "./sources/crates.ts";
+import { GithubSource } from "./sources/github.ts";
+import { GoproxySource } from "./sources/goproxy.ts";
+import { NpmSource } from "./sources/npm.ts";
+import type { SourceType } from "./types.ts";
+
+export type { SourceType };
+
+/** Per-source overrides threaded in from `[sources.*]` config. */
+export type SourceOptions = Readonly<{
+  baseUrl?: string;
+  token?: string;
+}>;
+
+export type VersionInfo = Readonly<{
+  version: string;
+  /** ISO 8601 publish timestamp, when the source exposes one. */
+  publishedAt?: string;
+  prerelease?: boolean;
+  /** Withdrawn on the registry (crates.io yank). */
+  yanked?: boolean;
+  /** Deprecation message from the registry (npm deprecate). */
+  deprecated?: string;
+  /** SPDX license expression, when the registry exposes one per version. */
+  license?: string;
+  /** Per-version download count, when the registry exposes one (crates.io). */
+  downloads?: number;
+}>;
+
+/** Result of a conditional lookup: fresh versions, or "cached copy still good". */
+export type ConditionalVersions =
+  | Readonly<{ notModified: true }>
+  | Readonly<{ notModified: false; versions: VersionInfo[]; etag?: string; lastModified?: string }>;
+
+export interface Source {
+  readonly type: SourceType;
+  /** List known versions, newest first. */
+  listVersions(identifier: string): Promise<VersionInfo[]>;
+  /**
+   * Conditional variant sending stored HTTP validators; a not-modified
+   * answer lets the cache refresh its TTL without re-downloading.
+   */
+  listVersionsConditional?(
+    identifier: string,
+    validators: Validators,
+  ): Promise<ConditionalVersions>;
+}
+
+/** Fallback TTL for cached version lists. */
+export const defaultCacheTtlMs = 60 * 60 * 1000;
+
+/** Short TTL for negative entries, so typo fixes don't wait an hour. */
+export const notFoundTtlMs = 5 * 60 * 1000;
+
+/** The registry answered 404: the package does not exist there. */
+export class UnknownPackageError extends TreeupdtError {
+  constructor(key: string) {
+    super("not-found", `unknown package: ${key} (not found upstream)`);
+    this.name = "UnknownPackageError";
+  }
+}
+
+/** Negative cache entry standing in for a version list. */
+function isNotFoundMarker(value: unknown): boolean {
+  return isRecord(value) && value["notFound"] === true;
+}
+
+/**
+ * Decorator serving version lists from the response cache while fresh, so
+ * repeated runs don't hammer the registries. Failures to read or write the
+ * cache never fail the lookup itself.
+ */
+export class CachedSource implements Source {
+  readonly #inner: Source;
+  readonly #cache: Cache;
+  readonly #ttlMs: number;
+  readonly #offline: boolean;
+
+  constructor(inner: Source, cache: Cache, ttlMs = defaultCacheTtlMs, offline = false) {
+    this.#inner = inner;
+    this.#cache = cache;
+    this.#ttlMs = ttlMs;
+    this.#offline = offline;
+  }
+
+  get type(): SourceType {
+    return this.#inner.type;
+  }
+
+  async listVersions(identifier: string): Promise<VersionInfo[]> {
+    const key = `${this.type}:${identifier}`;
+    const hit = await this.#cache.get(key).catch(() => null);
+    if (hit !== null && isFresh(hit) && Array.isArray(hit.value)) {
+      recordCacheAccess(true);
+      return hit.value as VersionInfo[];
+    }
+    if (hit !== null && isFresh(hit) && isNotFoundMarker(hit.value)) {
+      recordCacheAccess(true);
+      throw new UnknownPackageError(key);
+    }
+
+    // Offline mode never touches the network: expired entries are served
+    // with a warning, and uncached packages are a hard error.
+    if (this.#offline) {
+      if (hit !== null && Array.isArray(hit.value)) {
+        recordCacheAccess(true);
+        warn("offline: serving stale cache entry", {
+          key,
+          ...(hit.expiresAt !== undefined ? { expired: hit.expiresAt } : {}),
+        });
+        return hit.value as VersionInfo[];
+      }
+      recordCacheAccess(false);
+      throw new TreeupdtError("offline-miss", `offline: no cached data for ${key}`);
+    }
+    recordCacheAccess(false);
+
+    try {
+      return await this.#fetchFresh(identifier, key, hit);
+    } catch (err) {
+      // Negative caching: remember 404s briefly so a typo'd or private
+      // package doesn't cost a network round-trip every run.
+      if (err instanceof HttpStatusError && err.status === 404) {
+        await this.#cache.set(key, { notFound: true }, notFoundTtlMs).catch(() => undefined);
+        throw new UnknownPackageError(key);
+      }
+      throw err;
+    }
+  }
+
+  async #fetchFresh(
+    identifier: string,
+    key: string,
+    hit: CacheEntry | null,
+  ): Promise<VersionInfo[]> {
+    // Expired entry with validators: revalidate instead of re-downloading.
+    if (
+      hit !== null && Array.isArray(hit.value) &&
+      this.#inner.listVersionsConditional !== undefined &&
+      (hit.etag !== undefined || hit.lastModified !== undefined)
+    ) {
+      const result = await this.#inner.listVersionsConditional(identifier, {
+        ...(hit.etag !== undefined ? { etag: hit.etag } : {}),
+        ...(hit.lastModified !== undefined ? { lastModified: hit.lastModified } : {}),
+      });
+      if (result.notModified) {
+        debug("revalidated cache entry", { key });
+        await this.#cache.set(key, hit.value, this.#ttlMs, {
+          ...(hit.etag !== undefined ? { etag: hit.etag } : {}),
+          ...(hit.lastModified !== undefined ? { lastModified: hit.lastModified } : {}),
+        }).catch(() => undefined);
+        return hit.value as VersionInfo[];
+      }
+      await this.#cache.set(key, result.versions, this.#ttlMs, {
+        ...(result.etag !== undefined ? { etag: result.etag } : {}),
+        ...(result.lastModified !== undefined ? { lastModified: result.lastModified } : {}),
+      }).catch(() => undefined);
+      return result.versions;
+    }
+
+    if (this.#inner.listVersionsConditional !== undefined) {
+      const result = await this.#inner.listVersionsConditional(identifier, {});
+      if (!result.notModified) {
+        await this.#cache.set(key, result.versions, this.#ttlMs, {
+          ...(result.etag !== undefined ? { etag: result.etag } : {}),
+          ...(result.lastModified !== undefined ? { lastModified: result.lastModified } : {}),
+        }).catch(() => undefined);
+        return result.versions;
+      }
+    }
+
+    const versions = await this.#inner.listVersions(identifier);
+    await this.#cache.set(key, versions, this.#ttlMs).catch(() => undefined);
+    return versions;
+  }
+}
+
+/**
+ * Per-run memoization: each (source, identifier) pair is queried at most once
+ * per invocation, even when a package appears in many manifests. Sharing the
+ * in-flight promise also collapses concurrent lookups. Failures are memoized
+ * too; retrying within one run would just repeat the same answer.
+ */
+class MemoizedSource implements Source {
+  readonly #inner: Source;
+  readonly #memo = new Map<string, Promise<VersionInfo[]>>();
+
+  constructor(inner: Source) {
+    this.#inner = inner;
+  }
+
+  get type(): SourceType {
+    return this.#inner.type;
+  }
+
+  listVersions(identifier: string): Promise<VersionInfo[]> {
+    let pending = this.#memo.get(identifier);
+    if (pending === undefined) {
+      pending = this.#inner.listVersions(identifier);
+      this.#memo.set(identifier, pending);
+    }
+    return pending;
+  }
+}
+
+export class SourceRegistry {
+  readonly #sources = new Map<SourceType, Source>();
+
+  /** Chainable, so external sources can be stacked onto the defaults. */
+  register(source: Source): this {
+    this.#sources.set(source.type, new MemoizedSource(source));
+    return this;
+  }
+
+  get(type: SourceType): Source | null {
+    return this.#sources.get(type) ?? null;
+  }
+
+  get types(): SourceType[] {
+    return [...this.#sources.keys()];
+  }
+}
+
+export type RegistryOptions = Readonly<{
+  /** Serve only from cache; `TREEUPDT_OFFLINE=1` enables it as well. */
+  offline?: boolean;
+}>;
+
+export function defaultSourceRegistry(
+  config: Config = defaultConfig,
+  opts: RegistryOptions = {},
+): SourceRegistry {
+  const offline = opts.offline ?? Deno.env.get("TREEUPDT_OFFLINE") === "1";
+  const registry = new SourceRegistry();
+  const sourceOptions = (type: SourceType): SourceOptions => {
+    const sourceConfig = config.sources[type] ?? {};
+    const token = resolveSourceToken(sourceConfig);
+    return {
+      ...(sourceConfig.baseUrl !== undefined ? { baseUrl: sourceConfig.baseUrl } : {}),
+      ...(token !== undefined ? { token } : {}),
+    };
+  };
+  const enabled = (type: SourceType): boolean => config.sources[type]?.enabled !== false;
+  const cache = openCache(config.global.cacheBackend);
+  const cached = (source: Source): Source =>
+    new CachedSource(
+      source,
+      cache,
+      effectiveCacheTtlMs(config, source.type, defaultCacheTtlMs),
+      offline,
+    );
+
+  if (enabled("github")) registry.register(cached(new GithubSource(sourceOptions("github"))));
+  if (enabled("npm")) registry.register(cached(new NpmSource(sourceOptions("npm"))));
+  if (enabled("crates")) registry.register(cached(new CratesSource(sourceOptions("crates"))));
+  if (enabled("goproxy")) registry.register(cached(new GoproxySource(sourceOptions("goproxy"))));
+  return registry;
+}
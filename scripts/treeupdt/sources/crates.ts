@@ -0,0 +1,67 @@
+import { assertArray, assertRecord, assertString } from "../../updater/assert.ts";
+import { fetchTextConditional, type Validators } from "../http.ts";
+import type { ConditionalVersions, Source, SourceOptions, VersionInfo } from "../sources.ts";
+
+/** Source for crates.io; identifiers are crate names. */
+export class CratesSource implements Source {
+  readonly type = "crates" as const;
+  readonly #baseUrl: string;
+  readonly #token: string | undefined;
+
+  constructor(opts: SourceOptions = {}) {
+    this.#baseUrl = opts.baseUrl ?? "https://crates.io";
+    this.#token = opts.token;
+  }
+
+  async listVersions(identifier: string): Promise<VersionInfo[]> {
+    const result = await this.listVersionsConditional(identifier, {});
+    if (result.notModified) {
+      throw new Error(`Unexpected 304 without validators for ${identifier}`);
+    }
+    return result.versions;
+  }
+
+  async listVersionsConditional(
+    identifier: string,
+    validators: Validators,
+  ): Promise<ConditionalVersions> {
+    const url = `${this.#baseUrl}/api/v1/crates/${identifier}/versions`;
+    const res = await fetchTextConditional(url, {
+      headers: {
+        "User-Agent": "agentNix-updater",
+        "Accept": "application/json",
+        ...(this.#token !== undefined ? { "Authorization": this.#token } : {}),
+      },
+    }, validators);
+    if (res.notModified) return res;
+
+    const data: unknown = JSON.parse(res.text);
+    assertRecord(data, `crates.io ${identifier}`);
+    const rawVersions = data["versions"];
+    assertArray(rawVersions, `crates.io ${identifier}: versions`);
+
+    const versions: VersionInfo[] = [];
+    for (const [i, raw] of rawVersions.entries()) {
+      assertRecord(raw, `crates.io ${identifier}: versions[${i}]`);
+      const num = raw["num"];
+      assertString(num, `crates.io ${identifier}: versions[${i}].num`);
+      const createdAt = raw["created_at"];
+      const license = raw["license"];
+      const downloads = raw["downloads"];
+      versions.push({
+        version: num,
+        ...(typeof createdAt === "string" ? { publishedAt: createdAt } : {}),
+        ...(/[-+]/.test(num) ? { prerelease: true } : {}),
+        ...(raw["yanked"] === true ? { yanked: true } : {}),
+        ...(typeof license === "string" ? { license } : {}),
+        ...(typeof downloads === "number" ? { downloads } : {}),
+      });
+    }
+    return {
+      notModified: false,
+      versions,
+      ...(res.etag !== undefined ? { etag: res.etag } : {}),
+      ...(res.lastModified !== undefined ? { lastModified: res.lastModified } : {}),
+    };
+  }
+}
@@ -0,0 +1,86 @@
+import { assertArray, assertRecord, assertString } from "../../updater/assert.ts";
+import { buildGithubHeaders, resolveGithubToken } from "../../updater/github.ts";
+import { fetchJson, fetchTextConditional, type Validators } from "../http.ts";
+import type { ConditionalVersions, Source, SourceOptions, VersionInfo } from "../sources.ts";
+
+function normalizeTag(tag: string): string {
+  return tag.startsWith("v") ? tag.slice(1) : tag;
+}
+
+function parseRelease(data: unknown, context: string): VersionInfo | null {
+  assertRecord(data, `${context}: expected object`);
+  if (data["draft"] === true) return null;
+
+  const tagName = data["tag_name"];
+  assertString(tagName, `${context}: expected tag_name string`);
+
+  const publishedAt = data["published_at"];
+  return {
+    version: normalizeTag(tagName),
+    ...(typeof publishedAt === "string" ? { publishedAt } : {}),
+    ...(data["prerelease"] === true ? { prerelease: true } : {}),
+  };
+}
+
+/** Source for GitHub releases; identifiers are `owner/repo`. */
+export class GithubSource implements Source {
+  readonly type = "github" as const;
+  readonly #baseUrl: string;
+  readonly #token: string | undefined;
+
+  constructor(opts: SourceOptions = {}) {
+    this.#baseUrl = opts.baseUrl ?? "https://api.github.com";
+    this.#token = opts.token;
+  }
+
+  async listVersions(identifier: string): Promise<VersionInfo[]> {
+    const result = await this.listVersionsConditional(identifier, {});
+    if (result.notModified) {
+      throw new Error(`Unexpected 304 without validators for ${identifier}`);
+    }
+    return result.versions;
+  }
+
+  async listVersionsConditional(
+    identifier: string,
+    validators: Validators,
+  ): Promise<ConditionalVersions> {
+    const url = `${this.#baseUrl}/repos/${identifier}/releases?per_page=100`;
+    const res = await fetchTextConditional(url, {
+      headers: buildGithubHeaders(this.#token ?? resolveGithubToken()),
+    }, validators);
+    if (res.notModified) return res;
+
+    const data: unknown = JSON.parse(res.text);
+    assertArray(data, `GitHub releases ${identifier}`);
+
+    const versions: VersionInfo[] = [];
+    for (const [i, release] of data.entries()) {
+      const parsed = parseRelease(release, `GitHub releases ${identifier}[${i}]`);
+      if (parsed) versions.push(parsed);
+    }
+    return {
+      notModified: false,
+      versions,
+      ...(res.etag !== undefined ? { etag: res.etag } : {}),
+      ...(res.lastModified !== undefined ? { lastModified: res.lastModified } : {}),
+    };
+  }
+}
+
+/** Publish timestamp for a single tagged release, or null when unknown. */
+export async function fetchReleasePublishedAt(
+  owner: string,
+  repo: string,
+  tag: string,
+): Promise<string | null> {
+  const url = `https://api.github.com/repos/${owner}/${repo}/releases/tags/${tag}`;
+  try {
+    const data = await fetchJson(url, { headers: buildGithubHeaders(resolveGithubToken()) });
+    assertRecord(data, `GitHub release ${owner}/${repo}@${tag}`);
+    const publishedAt = data["published_at"];
+    return typeof publishedAt === "string" ? publishedAt : null;
+  } catch {
+    return null;
+  }
+}
@@ -0,0 +1,51 @@
+import { fetchTextConditional, type Validators } from "../http.ts";
+import { compareVersions } from "../../updater/version.ts";
+import type { ConditionalVersions, Source, SourceOptions, VersionInfo } from "../sources.ts";
+
+/** Case-encode a module path for the Go module proxy (`!a` for `A`). */
+export function escapeGoModulePath(modulePath: string): string {
+  return modulePath.replaceAll(/[A-Z]/g, (ch) => `!${ch.toLowerCase()}`);
+}
+
+/** Source for the Go module proxy; identifiers are module paths. */
+export class GoproxySource implements Source {
+  readonly type = "goproxy" as const;
+  readonly #baseUrl: string;
+
+  constructor(opts: SourceOptions = {}) {
+    this.#baseUrl = opts.baseUrl ?? "https://proxy.golang.org";
+  }
+
+  async listVersions(identifier: string): Promise<VersionInfo[]> {
+    const result = await this.listVersionsConditional(identifier, {});
+    if (result.notModified) {
+      throw new Error(`Unexpected 304 without validators for ${identifier}`);
+    }
+    return result.versions;
+  }
+
+  async listVersionsConditional(
+    identifier: string,
+    validators: Validators,
+  ): Promise<ConditionalVersions> {
+    const url = `${this.#baseUrl}/${escapeGoModulePath(identifier)}/@v/list`;
+    const res = await fetchTextConditional(url, {}, validators);
+    if (res.notModified) return res;
+
+    const versions: VersionInfo[] = res.text
+      .split("\n")
+      .map((line) => line.trim())
+      .filter(Boolean)
+      .map((version) => ({
+        version,
+        ...(version.includes("-") ? { prerelease: true } : {}),
+      }));
+    versions.sort((a, b) => compareVersions(b.version, a.version));
+    return {
+      notModified: false,
+      versions,
+      ...(res.etag !== undefined ? { etag: res.etag } : {}),
+      ...(res.lastModified !== undefined ? { lastModified: res.lastModified } : {}),
+    };
+  }
+}
@@ -0,0 +1,72 @@
+import { assertRecord, isRecord } from "../../updater/assert.ts";
+import { fetchTextConditional, type Validators } from "../http.ts";
+import { compareVersions } from "../../updater/version.ts";
+import type { ConditionalVersions, Source, SourceOptions, VersionInfo } from "../sources.ts";
+
+/** Source for the npm registry; identifiers are package names. */
+export class NpmSource implements Source {
+  readonly type = "npm" as const;
+  readonly #baseUrl: string;
+  readonly #token: string | undefined;
+
+  constructor(opts: SourceOptions = {}) {
+    this.#baseUrl = opts.baseUrl ?? "https://registry.npmjs.org";
+    this.#token = opts.token;
+  }
+
+  async listVersions(identifier: string): Promise<VersionInfo[]> {
+    const result = await this.listVersionsConditional(identifier, {});
+    if (result.notModified) {
+      throw new Error(`Unexpected 304 without validators for ${identifier}`);
+    }
+    return result.versions;
+  }
+
+  async listVersionsConditional(
+    identifier: string,
+    validators: Validators,
+  ): Promise<ConditionalVersions> {
+    const url = `${this.#baseUrl}/${encodeURIComponent(identifier)}`;
+    const res = await fetchTextConditional(url, {
+      headers: {
+        "Accept": "application/json",
+        ...(this.#token !== undefined ? { "Authorization": `Bearer ${this.#token}` } : {}),
+      },
+    }, validators);
+    if (res.notModified) return res;
+
+    const data: unknown = JSON.parse(res.text);
+    assertRecord(data, `npm package ${identifier}`);
+
+    const versionsTable = data["versions"];
+    assertRecord(versionsTable, `npm package ${identifier}: versions`);
+    const time = data["time"];
+    const timeTable = time !== undefined && time !== null ? time : {};
+    assertRecord(timeTable, `npm package ${identifier}: time`);
+
+    const versions: VersionInfo[] = [];
+    for (const [version, manifest] of Object.entries(versionsTable)) {
+      const publishedAt = timeTable[version];
+      const deprecated = isRecord(manifest) && typeof manifest["deprecated"] === "string"
+        ? manifest["deprecated"]
+        : undefined;
+      const license = isRecord(manifest) && typeof manifest["license"] === "string"
+        ? manifest["license"]
+        : undefined;
+      versions.push({
+        version,
+        ...(typeof publishedAt === "string" ? { publishedAt } : {}),
+        ...(/[-+]/.test(version) ? { prerelease: true } : {}),
+        ...(deprecated !== undefined ? { deprecated } : {}),
+        ...(license !== undefined ? { license } : {}),
+      });
+    }
+    versions.sort((a, b) => compareVersions(b.version, a.version));
+    return {
+      notModified: false,
+      versions,
+      ...(res.etag !== undefined ? { etag: res.etag } : {}),
+      ...(res.lastModified !== undefined ? { lastModified: res.lastModified } : {}),
+    };
+  }
+}
@@ -0,0 +1,64 @@
+import type { SourceSpan } from "./types.ts";
+
+/** Span of `length` characters starting at string index `start` in `content`. */
+export function spanAt(content: string, start: number, length: number): SourceSpan {
+  let line = 1;
+  let lineStart = 0;
+  for (let i = 0; i < start && i < content.length; i += 1) {
+    if (content[i] === "\n") {
+      line += 1;
+      lineStart = i + 1;
+    }
+  }
+  return { line, column: start - lineStart + 1, start, end: start + length };
+}
+
+/**
+ * Span of `literal` within a single line, given the line's 1-based number and
+ * the index where it starts in the file; null when the line doesn't contain it.
+ */
+export function spanInLine(
+  line: string,
+  lineNumber: number,
+  lineStart: number,
+  literal: string,
+): SourceSpan | null {
+  const column = line.indexOf(literal);
+  if (column === -1) return null;
+  return {
+    line: lineNumber,
+    column: column + 1,
+    start: lineStart + column,
+    end: lineStart + column + literal.length,
+  };
+}
+
+/** Like `spanInLine` for a double-quoted literal, spanning only the inside. */
+export function quotedSpanInLine(
+  line: string,
+  lineNumber: number,
+  lineStart: number,
+  literal: string,
+): SourceSpan | null {
+  const quoted = spanInLine(line, lineNumber, lineStart, `"${literal}"`);
+  if (quoted === null) return null;
+  return {
+    line: quoted.line,
+    column: quoted.column + 1,
+    start: quoted.start + 1,
+    end: quoted.end - 1,
+  };
+}
+
+/**
+ * Span of the first `"literal"` occurrence at or after `fromIndex`, spanning
+ * only the inside of the quotes; null when the content doesn't contain it.
+ */
+export function findQuotedSpan(
+  content: string,
+  literal: string,
+  fromIndex = 0,
+): SourceSpan | null {
+  const start = content.indexOf(`"${literal}"`, fromIndex);
+  return start === -1 ? null : spanAt(content, start + 1, literal.length);
+}
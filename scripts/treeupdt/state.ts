@@ -0,0 +1,93 @@
+import { join, resolve } from "node:path";
+
+import { isRecord } from "../updater/assert.ts";
+import type { UpdateEntry } from "./types.ts";
+
+let cacheDirOverride: string | null = null;
+
+/** Redirect the cache directory, from `global.cache-dir` once config loads. */
+export function setCacheDirOverride(dir: string): void {
+  cacheDirOverride = dir;
+}
+
+/**
+ * Root cache directory: `global.cache-dir` / `TREEUPDT_CACHE_DIR` when set
+ * (CI runners rarely persist the XDG path between jobs), else XDG conventions.
+ */
+export function treeupdtCacheDir(): string {
+  if (cacheDirOverride !== null) {
+    return cacheDirOverride;
+  }
+  const env = Deno.env.get("TREEUPDT_CACHE_DIR");
+  if (env !== undefined && env !== "") {
+    return env;
+  }
+  const xdg = Deno.env.get("XDG_CACHE_HOME");
+  if (xdg !== undefined && xdg !== "") {
+    return join(xdg, "treeupdt");
+  }
+  const home = Deno.env.get("HOME") ?? ".";
+  return join(home, ".cache", "treeupdt");
+}
+
+/** FNV-1a hex digest, enough to key state and cache files. */
+export function fnv1a(text: string): string {
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < text.length; i += 1) {
+    hash ^= text.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193) >>> 0;
+  }
+  return hash.toString(16).padStart(8, "0");
+}
+
+function statePath(root: string): string {
+  return join(treeupdtCacheDir(), "state", `check-${fnv1a(resolve(root))}.json`);
+}
+
+/** Check entries from the previous run for this repo, or null on first run. */
+export async function loadPreviousEntries(root: string): Promise<UpdateEntry[] | null> {
+  try {
+    const parsed: unknown = JSON.parse(await Deno.readTextFile(statePath(root)));
+    if (!Array.isArray(parsed)) return null;
+    // State files are written by us; a shallow shape check guards against
+    // corruption and older formats without a full parser.
+    if (!parsed.every((item) => isRecord(item) && typeof item["name"] === "string")) {
+      return null;
+    }
+    return parsed as UpdateEntry[];
+  } catch (err) {
+    if (err instanceof Deno.errors.NotFound) return null;
+    throw err;
+  }
+}
+
+export async function saveEntries(
+  root: string,
+  entries: readonly UpdateEntry[],
+): Promise<void> {
+  const path = statePath(root);
+  await Deno.mkdir(join(treeupdtCacheDir(), "state"), { recursive: true });
+  await Deno.writeTextFile(path, `${JSON.stringify(entries, null, 2)}\n`);
+}
+
+function updateKey(entry: UpdateEntry): string {
+  return `${entry.file} ${entry.name} ${entry.latest ?? ""}`;
+}
+
+/**
+ * Keep only updates that were not already reported by the previous run, so
+ * recurring jobs stay quiet until something actually changes. Errors and
+ * non-update entries pass through untouched.
+ */
+export function changedOnly(
+  entries: readonly UpdateEntry[],
+  previous: readonly UpdateEntry[] | null,
+): UpdateEntry[] {
+  if (previous === null) return [...entries];
+  const seen = new Set(
+    previous
+      .filter((entry) => entry.updateAvailable === true)
+      .map(updateKey),
+  );
+  return entries.filter((entry) => entry.updateAvailable !== true || !seen.has(updateKey(entry)));
+}
@@ -0,0 +1,47 @@
+import { classifyChange, isNewerVersion } from "./semverRange.ts";
+import type { VersionInfo } from "./sources.ts";
+import type { SemverLevel, Strategy } from "./types.ts";
+
+export const strategies = ["latest", "conservative", "patch", "none"] as const;
+
+export function isStrategy(value: string): value is Strategy {
+  return (strategies as readonly string[]).includes(value);
+}
+
+/**
+ * Whether a strategy permits a change of the given magnitude. An
+ * unclassifiable change (non-semver versions) is only taken by `latest`,
+ * since the restrictive strategies can't prove it stays in bounds.
+ */
+export function allowsLevel(strategy: Strategy, level: SemverLevel | null): boolean {
+  switch (strategy) {
+    case "latest":
+      return true;
+    case "conservative":
+      return level === "minor" || level === "patch";
+    case "patch":
+      return level === "patch";
+    case "none":
+      return false;
+  }
+}
+
+/**
+ * The newest stable version the strategy would actually apply from `current`,
+ * or null when nothing qualifies. `versions` must be sorted newest-first, as
+ * sources return them.
+ */
+export function selectTarget(
+  current: string,
+  versions: readonly VersionInfo[],
+  strategy: Strategy,
+): VersionInfo | null {
+  for (const version of versions) {
+    if (version.prerelease === true || version.yanked === true) continue;
+    if (!isNewerVersion(current, version.version)) continue;
+    if (allowsLevel(strategy, classifyChange(current, version.version))) {
+      return version;
+    }
+  }
+  return null;
+}
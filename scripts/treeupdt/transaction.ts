@@ -0,0 +1,87 @@
+import { dirname, join } from "node:path";
+
+/**
+ * Staged multi-file write. All new contents are computed up front, written to
+ * temp files next to their targets, and renamed into place only once every
+ * temp write has succeeded. If a rename fails midway, already-replaced files
+ * are restored from their original contents, so a logical change touching
+ * several files (manifest + lockfile + hash) lands either fully or not at all.
+ *
+ * `commit` doubles as a cancellation point: when the given signal is already
+ * aborted it throws before touching any file, so a Ctrl-C mid-run never
+ * leaves a half-applied edit behind.
+ */
+export class FileTransaction {
+  readonly #staged = new Map<string, string>();
+
+  stage(path: string, contents: string): void {
+    this.#staged.set(path, contents);
+  }
+
+  get isEmpty(): boolean {
+    return this.#staged.size === 0;
+  }
+
+  get paths(): string[] {
+    return [...this.#staged.keys()];
+  }
+
+  async commit(signal?: AbortSignal): Promise<void> {
+    if (signal?.aborted === true) {
+      throw new Error("cancelled before any file was written");
+    }
+    const entries = [...this.#staged.entries()];
+    const tempPaths = new Map<string, string>();
+    const originals = new Map<string, string | null>();
+
+    for (const [path] of entries) {
+      try {
+        originals.set(path, await Deno.readTextFile(path));
+      } catch (err) {
+        if (!(err instanceof Deno.errors.NotFound)) throw err;
+        originals.set(path, null);
+      }
+    }
+
+    try {
+      for (const [path, contents] of entries) {
+        const tempPath = join(
+          dirname(path),
+          `.${crypto.randomUUID().slice(0, 8)}.treeupdt-tmp`,
+        );
+        await Deno.writeTextFile(tempPath, contents);
+        tempPaths.set(path, tempPath);
+      }
+    } catch (err) {
+      await this.#removeTemps(tempPaths);
+      throw err;
+    }
+
+    const renamed: string[] = [];
+    try {
+      for (const [path, tempPath] of tempPaths) {
+        await Deno.rename(tempPath, path);
+        renamed.push(path);
+      }
+    } catch (err) {
+      for (const path of renamed) {
+        const original = originals.get(path);
+        if (original === null || original === undefined) {
+          await Deno.remove(path).catch(() => undefined);
+        } else {
+          await Deno.writeTextFile(path, original).catch(() => undefined);
+        }
+      }
+      await this.#removeTemps(tempPaths);
+      throw err;
+    }
+
+    this.#staged.clear();
+  }
+
+  async #removeTemps(tempPaths: ReadonlyMap<string, string>): Promise<void> {
+    for (const tempPath of tempPaths.values()) {
+      await Deno.remove(tempPath).catch(() => undefined);
+    }
+  }
+}
@@ -0,0 +1,157 @@
+export const knownFileTypes = ["go", "npm", "cargo", "nix"] as const;
+
+export type KnownFileType = (typeof knownFileTypes)[number];
+
+/** Built-in manifest types, or any string for externally registered scanners. */
+export type FileType = KnownFileType | (string & {});
+
+export const knownSourceTypes = ["github", "npm", "crates", "goproxy"] as const;
+
+export type KnownSourceType = (typeof knownSourceTypes)[number];
+
+/** Built-in registries, or any string for externally registered sources. */
+export type SourceType = KnownSourceType | (string & {});
+
+export type SourceHint = Readonly<{
+  source: SourceType;
+  /** Identifier in the source's namespace, e.g. `owner/repo` for GitHub. */
+  identifier: string;
+}>;
+
+/** Inline `# treeupdt: ...` directives attached to a manifest entry. */
+export type Annotation = Readonly<{
+  /** Skip the package unconditionally. */
+  ignore?: boolean;
+  /** Skip the package until this ISO date; expires automatically. */
+  ignoreUntil?: string;
+  /** Never propose major updates for this package. */
+  ignoreMajor?: boolean;
+  /** Free-form justification, surfaced in check output. */
+  reason?: string;
+}>;
+
+/**
+ * Location of a version literal in its manifest, as captured by the scanner.
+ * `line`/`column` are 1-based for display (`go.mod:12:18`); `start`/`end` are
+ * string indexes into the file content for editors and precise rewrites.
+ */
+export type SourceSpan = Readonly<{
+  line: number;
+  column: number;
+  start: number;
+  end: number;
+}>;
+
+/**
+ * Structured identity of a manifest entry: which file it lives in, which
+ * section (or kind) of that file, and its bare name there. This is what code
+ * should match on; the flat `<section>-<name>` display name is derived from it
+ * by `conventionalName` and kept for config keys and human output.
+ */
+export type PackageId = Readonly<{
+  file: string;
+  /** Manifest section or kind: `dependencies`, `require`, `runtime`, ... */
+  section: string;
+  name: string;
+}>;
+
+/** Flat display name for an id, e.g. `dependencies-react`. */
+export function conventionalName(id: PackageId): string {
+  return id.section === "" ? id.name : `${id.section}-${id.name}`;
+}
+
+export type Package = Readonly<{
+  id: PackageId;
+  /** Display name following the `<section>-<name>` convention; see `conventionalName`. */
+  name: string;
+  version: string;
+  file: string;
+  fileType: FileType;
+  sourceHints: readonly SourceHint[];
+  /** endoflife.date product slug for runtime pins (`go`, `nodejs`, ...). */
+  eolProduct?: string;
+  annotation?: Annotation;
+  /** Where the version literal lives; absent when the scanner can't tell. */
+  span?: SourceSpan;
+}>;
+
+export type UpdateOutcome = Readonly<{
+  oldVersion: string;
+}>;
+
+export type SemverLevel = "major" | "minor" | "patch";
+
+/**
+ * How far a package may be bumped: `latest` takes anything, `conservative`
+ * stays within the current major, `patch` within the current minor, `none`
+ * freezes the package entirely.
+ */
+export type Strategy = "latest" | "conservative" | "patch" | "none";
+
+export type VersionStatus = "ok" | "yanked" | "deprecated";
+
+/** What a non-preferred source reported, kept on the reconciled entry. */
+export type AlternateResult = Readonly<{
+  source: string;
+  identifier?: string;
+  latest?: string;
+  error?: string;
+  /** Stable failure code from errors.ts, when the source failed. */
+  errorCode?: string;
+}>;
+
+/**
+ * One check result for a package/source pair. Fields beyond the scan metadata
+ * are filled in as the check progresses and stay absent on failure, with
+ * `error` carrying the reason. Shared by every output format and by library
+ * consumers, so extend it rather than inventing parallel shapes.
+ */
+export type UpdateEntry = {
+  /** Structured identity, carried over from the scanned package. */
+  id?: PackageId;
+  name: string;
+  file: string;
+  fileType: FileType;
+  current: string;
+  /** A SourceType, or `endoflife` for runtime EOL checks. */
+  source: string;
+  identifier?: string;
+  latest?: string;
+  updateAvailable?: boolean;
+  semverLevel?: SemverLevel;
+  strategy?: Strategy;
+  /** A newer release exists but the effective strategy rules it out. */
+  blockedByStrategy?: boolean;
+  latestPublishedAt?: string;
+  /** How many stable releases sit between current and the newest one. */
+  releasesBehind?: number;
+  currentPublishedAt?: string;
+  currentVersionStatus?: VersionStatus;
+  deprecationMessage?: string;
+  currentLicense?: string;
+  latestLicense?: string;
+  eol?: boolean;
+  eolDate?: string;
+  error?: string;
+  /** Stable failure code from errors.ts, alongside the error message. */
+  errorCode?: string;
+  /** The source answered 404: likely a typo'd or private package. */
+  unknownPackage?: boolean;
+  /** Results from the package's other sources after reconciliation. */
+  alternates?: readonly AlternateResult[];
+  /** Skipped because of an active `# treeupdt: ignore` annotation. */
+  ignored?: boolean;
+  ignoreReason?: string;
+  /** Name of the config group the package belongs to, if any. */
+  group?: string;
+  /** `pin-version` from the config, when the package is pinned. */
+  pinVersion?: string;
+  /** The manifest's actual version differs from the pin. */
+  pinDrift?: boolean;
+  /** Span of the version literal, carried over from the scanned package. */
+  span?: SourceSpan;
+};
+
+export type UpdateReport = Readonly<{
+  entries: readonly UpdateEntry[];
+}>;
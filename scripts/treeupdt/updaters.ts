@@ -0,0 +1,65 @@
+import { CargoUpdater } from "./updaters/cargo.ts";
+import { GoUpdater } from "./updaters/go.ts";
+import { NixGithubUpdater } from "./updaters/nix.ts";
+import { NpmUpdater } from "./updaters/npm.ts";
+import type { FileType, UpdateOutcome } from "./types.ts";
+
+export type UpdateOptions = Readonly<{
+  /** Refresh companion lockfiles (go.sum etc.) after the manifest edit. */
+  sync?: boolean;
+  /** Cooldown like `7d`; refuse versions published more recently than this. */
+  minimumReleaseAge?: string;
+  /** Cancellation: an aborted signal fails the edit before any file write. */
+  signal?: AbortSignal;
+}>;
+
+export interface Updater {
+  readonly fileType: FileType;
+  /** Whether this updater handles the given manifest path. */
+  matches(path: string): boolean;
+  apply(
+    file: string,
+    packageName: string,
+    newVersion: string,
+    opts: UpdateOptions,
+  ): Promise<UpdateOutcome>;
+}
+
+/**
+ * Updaters keyed by file type. Downstream users can `register()` their own
+ * implementations (Helm, Terraform, ...) without modifying this module;
+ * registering an existing file type replaces the default.
+ */
+export class UpdaterRegistry {
+  readonly #updaters = new Map<FileType, Updater>();
+
+  /** Chainable, so external updaters can be stacked onto the defaults. */
+  register(updater: Updater): this {
+    this.#updaters.set(updater.fileType, updater);
+    return this;
+  }
+
+  get(fileType: FileType): Updater | null {
+    return this.#updaters.get(fileType) ?? null;
+  }
+
+  forFile(path: string): Updater | null {
+    for (const updater of this.#updaters.values()) {
+      if (updater.matches(path)) return updater;
+    }
+    return null;
+  }
+
+  get fileTypes(): FileType[] {
+    return [...this.#updaters.keys()];
+  }
+}
+
+export function defaultUpdaterRegistry(): UpdaterRegistry {
+  const registry = new UpdaterRegistry();
+  registry.register(new CargoUpdater());
+  registry.register(new GoUpdater());
+  registry.register(new NixGithubUpdater());
+  registry.register(new NpmUpdater());
+  return registry;
+}
@@ -0,0 +1,132 @@
+import { basename, dirname, join } from "node:path";
+
+import { fileExists } from "../../updater/fs.ts";
+import { assertReleaseOldEnough } from "../releaseAge.ts";
+import { parseCargoDependencies } from "../scanners/cargo.ts";
+import { CratesSource } from "../sources/crates.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { UpdateOptions, Updater } from "../updaters.ts";
+import type { UpdateOutcome } from "../types.ts";
+
+export type CargoUpdateOptions = Readonly<{
+  /** Cooldown like `7d`; refuse versions published more recently than this. */
+  minimumReleaseAge?: string;
+  /** Cancellation: checked before the Cargo.toml write. */
+  signal?: AbortSignal;
+}>;
+
+function rewriteDependencyVersion(content: string, name: string, newVersion: string): string {
+  const lines = content.split("\n");
+  let section = "";
+  let replaced = false;
+
+  const rewritten = lines.map((line) => {
+    const sectionMatch = line.trim().match(/^\[([^\]]+)\]$/);
+    if (sectionMatch?.[1]) {
+      section = sectionMatch[1];
+      return line;
+    }
+    if (!/dependencies$/.test(section)) return line;
+
+    const entryMatch = line.match(new RegExp(`^(\\s*)${name}\\s*=`));
+    if (!entryMatch) return line;
+
+    const updated = line
+      .replace(new RegExp(`^(\\s*${name}\\s*=\\s*)"[^"]+"`), `$1"${newVersion}"`)
+      .replace(/(\bversion\s*=\s*)"[^"]+"/, `$1"${newVersion}"`);
+    if (updated !== line) replaced = true;
+    return updated;
+  });
+
+  if (!replaced) {
+    throw new Error(`Cargo.toml: no versioned dependency entry found for ${name}`);
+  }
+  return rewritten.join("\n");
+}
+
+/** Walk up from a member manifest to the root declaring `[workspace.dependencies] <name>`. */
+async function findWorkspaceRoot(memberPath: string, name: string): Promise<string | null> {
+  let dir = dirname(memberPath);
+  while (true) {
+    const parent = dirname(dir);
+    if (parent === dir) return null;
+    dir = parent;
+
+    const candidate = join(dir, "Cargo.toml");
+    if (!(await fileExists(candidate))) continue;
+    const deps = parseCargoDependencies(await Deno.readTextFile(candidate));
+    if (deps.some((d) => d.section === "workspace.dependencies" && d.name === name)) {
+      return candidate;
+    }
+  }
+}
+
+export async function applyCargoUpdate(
+  cargoTomlPath: string,
+  packageName: string,
+  newVersion: string,
+  opts: CargoUpdateOptions = {},
+): Promise<UpdateOutcome> {
+  const content = await Deno.readTextFile(cargoTomlPath);
+  const deps = parseCargoDependencies(content);
+  const dep = deps.find((d) => d.name === packageName);
+  if (!dep) {
+    throw new Error(`${cargoTomlPath}: no dependency entry found for ${packageName}`);
+  }
+
+  if (opts.minimumReleaseAge !== undefined) {
+    const versions = await new CratesSource().listVersions(packageName);
+    assertReleaseOldEnough(versions, newVersion, opts.minimumReleaseAge);
+  }
+
+  // Members inheriting via `workspace = true` are edited at the workspace root.
+  let targetPath = cargoTomlPath;
+  let targetContent = content;
+  let oldVersion = dep.version;
+  if (dep.workspace) {
+    const root = await findWorkspaceRoot(cargoTomlPath, packageName);
+    if (root === null) {
+      throw new Error(
+        `${cargoTomlPath}: ${packageName} inherits from the workspace, ` +
+          `but no root [workspace.dependencies] entry was found`,
+      );
+    }
+    targetPath = root;
+    targetContent = await Deno.readTextFile(root);
+    oldVersion = parseCargoDependencies(targetContent)
+      .find((d) => d.section === "workspace.dependencies" && d.name === packageName)
+      ?.version ?? null;
+  }
+
+  const transaction = new FileTransaction();
+  transaction.stage(targetPath, rewriteDependencyVersion(targetContent, packageName, newVersion));
+  await transaction.commit(opts.signal);
+
+  if (targetPath !== cargoTomlPath) {
+    console.log(`Redirected workspace-inherited edit to ${targetPath}`);
+  }
+
+  return { oldVersion: oldVersion ?? "unknown" };
+}
+
+export class CargoUpdater implements Updater {
+  readonly fileType = "cargo" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "Cargo.toml";
+  }
+
+  apply(
+    file: string,
+    packageName: string,
+    newVersion: string,
+    opts: UpdateOptions,
+  ): Promise<UpdateOutcome> {
+    return applyCargoUpdate(file, packageName, newVersion, {
+      ...(opts.minimumReleaseAge !== undefined
+        ? { minimumReleaseAge: opts.minimumReleaseAge }
+        : {}),
+      ...(opts.signal !== undefined ? { signal: opts.signal } : {}),
+    });
+  }
+}
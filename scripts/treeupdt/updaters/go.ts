@@ -0,0 +1,159 @@
+import { basename, dirname } from "node:path";
+
+import { runChecked } from "../../updater/command.ts";
+import { assertReleaseOldEnough } from "../releaseAge.ts";
+import { GoproxySource } from "../sources/goproxy.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { UpdateOptions, Updater } from "../updaters.ts";
+import type { UpdateOutcome } from "../types.ts";
+
+export type GoUpdateOptions = Readonly<{
+  /** Fetch the module and refresh go.sum after rewriting go.mod. Defaults to true. */
+  sync?: boolean;
+  /** Cooldown like `7d`; refuse versions published more recently than this. */
+  minimumReleaseAge?: string;
+  /** Cancellation: checked before the go.mod write and before the sync. */
+  signal?: AbortSignal;
+}>;
+
+function rewriteRequireLine(
+  line: string,
+  modulePath: string,
+  newModulePath: string,
+  newVersion: string,
+): string | null {
+  const match = line.match(/^(\s*)(?:require\s+)?(\S+)\s+(v\S+)(\s*\/\/.*)?$/);
+  if (!match) return null;
+  const [, indent, path, , comment] = match;
+  if (path !== modulePath) return null;
+  const prefix = line.trimStart().startsWith("require ") ? `${indent ?? ""}require ` : indent ?? "";
+  return `${prefix}${newModulePath} ${newVersion}${comment ?? ""}`;
+}
+
+/** `/vN` module path suffix required for major version N >= 2, else null. */
+export function goMajorSuffix(version: string): string | null {
+  const major = Number(version.replace(/^v/, "").split(".")[0] ?? "");
+  if (!Number.isInteger(major) || major < 2) return null;
+  return `/v${major}`;
+}
+
+/**
+ * Go modules at major version 2+ live under a `/vN` path suffix; moving to a
+ * new major means the module path itself changes (`…/v2` -> `…/v3`).
+ */
+export function bumpModulePathForMajor(modulePath: string, newVersion: string): string {
+  const base = modulePath.replace(/\/v\d+$/, "");
+  const suffix = goMajorSuffix(newVersion);
+  return suffix === null ? base : `${base}${suffix}`;
+}
+
+export type GoRewriteResult = Readonly<{
+  content: string;
+  oldVersion: string;
+  newModulePath: string;
+}>;
+
+export function rewriteGoMod(
+  content: string,
+  modulePath: string,
+  newVersion: string,
+): GoRewriteResult {
+  const newModulePath = bumpModulePathForMajor(modulePath, newVersion);
+  const lines = content.split("\n");
+  let inRequireBlock = false;
+  let oldVersion: string | null = null;
+
+  const rewritten = lines.map((line) => {
+    const trimmed = line.trim();
+    if (trimmed === "require (") {
+      inRequireBlock = true;
+      return line;
+    }
+    if (inRequireBlock && trimmed === ")") {
+      inRequireBlock = false;
+      return line;
+    }
+
+    if (!inRequireBlock && !trimmed.startsWith("require ")) return line;
+
+    const updated = rewriteRequireLine(line, modulePath, newModulePath, newVersion);
+    if (updated === null) return line;
+    const versionMatch = line.match(/\s(v\S+)/);
+    oldVersion = versionMatch?.[1] ?? "unknown";
+    return updated;
+  });
+
+  if (oldVersion === null) {
+    throw new Error(`go.mod: no require entry found for ${modulePath}`);
+  }
+  return { content: rewritten.join("\n"), oldVersion, newModulePath };
+}
+
+/**
+ * Fetch the updated module set and rewrite go.sum so the tree stays buildable
+ * after a go.mod bump.
+ */
+export async function syncGoSum(goModPath: string): Promise<void> {
+  await runChecked("go", ["mod", "tidy"], { cwd: dirname(goModPath) });
+}
+
+export async function applyGoUpdate(
+  goModPath: string,
+  modulePath: string,
+  newVersion: string,
+  opts: GoUpdateOptions = {},
+): Promise<UpdateOutcome> {
+  const content = await Deno.readTextFile(goModPath);
+  const rewrite = rewriteGoMod(content, modulePath, newVersion);
+
+  if (opts.minimumReleaseAge !== undefined) {
+    // The new module path, since a major bump moves the version list too.
+    const versions = await new GoproxySource().listVersions(rewrite.newModulePath);
+    assertReleaseOldEnough(versions, newVersion, opts.minimumReleaseAge);
+  }
+
+  const transaction = new FileTransaction();
+  transaction.stage(goModPath, rewrite.content);
+  await transaction.commit(opts.signal);
+
+  if (rewrite.newModulePath !== modulePath) {
+    console.log(
+      `Module path changed: ${modulePath} -> ${rewrite.newModulePath}; ` +
+        `import paths in Go sources must be updated to match`,
+    );
+  }
+
+  if (opts.signal?.aborted === true) {
+    console.log("Cancelled before go.sum sync; go.sum may be stale");
+  } else if (opts.sync ?? true) {
+    console.log("Syncing go.sum (go mod tidy)...");
+    await syncGoSum(goModPath);
+  } else {
+    console.log("Skipping go.sum sync (--no-sync); go.sum may be stale");
+  }
+
+  return { oldVersion: rewrite.oldVersion };
+}
+
+export class GoUpdater implements Updater {
+  readonly fileType = "go" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "go.mod";
+  }
+
+  apply(
+    file: string,
+    packageName: string,
+    newVersion: string,
+    opts: UpdateOptions,
+  ): Promise<UpdateOutcome> {
+    return applyGoUpdate(file, packageName, newVersion, {
+      sync: opts.sync ?? true,
+      ...(opts.minimumReleaseAge !== undefined
+        ? { minimumReleaseAge: opts.minimumReleaseAge }
+        : {}),
+      ...(opts.signal !== undefined ? { signal: opts.signal } : {}),
+    });
+  }
+}
@@ -0,0 +1,188 @@
+import { basename } from "node:path";
+
+import { calculateUrlHash } from "../../updater/hash.ts";
+import { isOldEnough, parseDuration } from "../releaseAge.ts";
+import { fetchReleasePublishedAt } from "../sources/github.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { UpdateOptions, Updater } from "../updaters.ts";
+import type { UpdateOutcome } from "../types.ts";
+
+export type NixUpdateOptions = Readonly<{
+  /** Cooldown like `7d`; refuse versions published more recently than this. */
+  minimumReleaseAge?: string;
+  /** Cancellation: checked before the package.nix write. */
+  signal?: AbortSignal;
+}>;
+
+export type FetchFromGithubInfo = Readonly<{
+  owner: string;
+  repo: string;
+  revTemplate: string;
+  hash: string;
+}>;
+
+function matchAttr(block: string, name: string): string | null {
+  const match = block.match(new RegExp(`\\b${name}\\s*=\\s*"([^"]*)"`));
+  return match?.[1] ?? null;
+}
+
+/**
+ * Index of the `{` opening the `fetchFromGitHub { ... }` call. Occurrences
+ * not followed by a brace -- like the formal parameter in the file's lambda
+ * head -- are not call sites and are skipped.
+ */
+function findFetchFromGithubCall(content: string): number {
+  let from = 0;
+  let start: number;
+  while ((start = content.indexOf("fetchFromGitHub", from)) !== -1) {
+    let i = start + "fetchFromGitHub".length;
+    while (/\s/.test(content[i] ?? "")) i += 1;
+    if (content[i] === "{") return i;
+    from = start + 1;
+  }
+  return -1;
+}
+
+/**
+ * Index of the `}` matching the brace at `open`, tracking string bodies and
+ * `${...}` interpolations so `rev = "v${version}"` doesn't end the attrset
+ * early. Returns -1 when the braces never balance.
+ */
+function matchingBrace(content: string, open: number): number {
+  const stack: ("attrset" | "interpolation")[] = [];
+  let inString = false;
+  for (let i = open; i < content.length; i += 1) {
+    const ch = content[i];
+    if (inString) {
+      if (ch === "\\") {
+        i += 1;
+      } else if (ch === "$" && content[i + 1] === "{") {
+        stack.push("interpolation");
+        inString = false;
+        i += 1;
+      } else if (ch === '"') {
+        inString = false;
+      }
+      continue;
+    }
+    if (ch === '"') {
+      inString = true;
+    } else if (ch === "{") {
+      stack.push("attrset");
+    } else if (ch === "}") {
+      if (stack.pop() === "interpolation") {
+        inString = true;
+      } else if (stack.length === 0) {
+        return i;
+      }
+    }
+  }
+  return -1;
+}
+
+export function parseFetchFromGithub(content: string): FetchFromGithubInfo {
+  const open = findFetchFromGithubCall(content);
+  if (open === -1) {
+    throw new Error("package.nix: no fetchFromGitHub call found");
+  }
+  const close = matchingBrace(content, open);
+  if (close === -1) {
+    throw new Error("package.nix: malformed fetchFromGitHub call");
+  }
+  const block = content.slice(open, close + 1);
+
+  const owner = matchAttr(block, "owner");
+  const repo = matchAttr(block, "repo");
+  const revTemplate = matchAttr(block, "rev");
+  const hash = matchAttr(block, "hash");
+  if (!owner || !repo || !revTemplate || !hash) {
+    throw new Error("package.nix: fetchFromGitHub must set owner, repo, rev, and hash");
+  }
+  return { owner, repo, revTemplate, hash };
+}
+
+/** Substitute `${version}` in a Nix string interpolation like `v${version}`. */
+export function resolveRev(revTemplate: string, version: string): string {
+  return revTemplate.replaceAll("${version}", version);
+}
+
+export function parseVersionAttr(content: string): string {
+  const match = content.match(/\bversion\s*=\s*"([^"]+)"/);
+  if (!match?.[1]) {
+    throw new Error("package.nix: no version attribute found");
+  }
+  return match[1];
+}
+
+export function rewriteVersion(content: string, newVersion: string): string {
+  const pattern = /(\bversion\s*=\s*")([^"]+)(")/;
+  if (!pattern.test(content)) {
+    throw new Error("package.nix: no version attribute found");
+  }
+  return content.replace(pattern, `$1${newVersion}$3`);
+}
+
+/**
+ * Bump a `package.nix` that fetches its source with `fetchFromGitHub`:
+ * rewrite `version`, resolve the new rev from the rev template, prefetch the
+ * source tree, and rewrite `hash` in the same pass. Updating only `version`
+ * is never sufficient for these derivations.
+ */
+export async function applyNixGithubUpdate(
+  packageNixPath: string,
+  newVersion: string,
+  opts: NixUpdateOptions = {},
+): Promise<UpdateOutcome> {
+  const content = await Deno.readTextFile(packageNixPath);
+  const oldVersion = parseVersionAttr(content);
+  const fetcher = parseFetchFromGithub(content);
+  const rev = resolveRev(fetcher.revTemplate, newVersion);
+
+  if (opts.minimumReleaseAge !== undefined) {
+    const minAgeMs = parseDuration(opts.minimumReleaseAge);
+    const publishedAt = await fetchReleasePublishedAt(fetcher.owner, fetcher.repo, rev);
+    if (
+      publishedAt !== null &&
+      !isOldEnough({ version: newVersion, publishedAt }, minAgeMs, Date.now())
+    ) {
+      throw new Error(
+        `${newVersion} was published at ${publishedAt}, ` +
+          `younger than the configured minimum-release-age of ${opts.minimumReleaseAge}`,
+      );
+    }
+  }
+
+  console.log(`Prefetching ${fetcher.owner}/${fetcher.repo} at ${rev}...`);
+  const url = `https://github.com/${fetcher.owner}/${fetcher.repo}/archive/${rev}.tar.gz`;
+  const newHash = await calculateUrlHash(url, { unpack: true });
+
+  const rewritten = rewriteVersion(content, newVersion).replace(fetcher.hash, newHash);
+
+  const transaction = new FileTransaction();
+  transaction.stage(packageNixPath, rewritten);
+  await transaction.commit(opts.signal);
+
+  return { oldVersion };
+}
+
+export class NixGithubUpdater implements Updater {
+  readonly fileType = "nix" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "package.nix";
+  }
+
+  apply(
+    file: string,
+    _packageName: string,
+    newVersion: string,
+    opts: UpdateOptions,
+  ): Promise<UpdateOutcome> {
+    return applyNixGithubUpdate(file, newVersion, {
+      ...(opts.minimumReleaseAge !== undefined
+        ? { minimumReleaseAge: opts.minimumReleaseAge }
+        : {}),
+      ...(opts.signal !== undefined ? { signal: opts.signal } : {}),
+    });
+  }
+}
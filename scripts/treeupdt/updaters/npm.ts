@@ -0,0 +1,96 @@
+import { basename } from "node:path";
+
+import { assertRecord } from "../../updater/assert.ts";
+import { assertReleaseOldEnough } from "../releaseAge.ts";
+import { rewriteRange } from "../semverRange.ts";
+import { NpmSource } from "../sources/npm.ts";
+import { FileTransaction } from "../transaction.ts";
+import type { UpdateOptions, Updater } from "../updaters.ts";
+import type { UpdateOutcome } from "../types.ts";
+
+export type NpmUpdateOptions = Readonly<{
+  /** Cooldown like `7d`; refuse versions published more recently than this. */
+  minimumReleaseAge?: string;
+  /** Cancellation: checked before the package.json write. */
+  signal?: AbortSignal;
+}>;
+
+export const npmDependencySections = [
+  "dependencies",
+  "devDependencies",
+  "peerDependencies",
+  "optionalDependencies",
+  "overrides",
+  "resolutions",
+] as const;
+
+export async function applyNpmUpdate(
+  packageJsonPath: string,
+  packageName: string,
+  newVersion: string,
+  opts: NpmUpdateOptions = {},
+): Promise<UpdateOutcome> {
+  const content = await Deno.readTextFile(packageJsonPath);
+  const parsed: unknown = JSON.parse(content);
+  assertRecord(parsed, `${packageJsonPath}: expected object`);
+
+  let rewritten = content;
+  let oldRange: string | null = null;
+
+  for (const section of npmDependencySections) {
+    const deps = parsed[section];
+    if (deps === undefined) continue;
+    assertRecord(deps, `${packageJsonPath}.${section}: expected object`);
+
+    const range = deps[packageName];
+    if (typeof range !== "string") continue;
+
+    oldRange ??= range;
+    const newRange = rewriteRange(range, newVersion);
+    if (newRange === range) continue;
+
+    rewritten = rewritten.replace(
+      `"${packageName}": ${JSON.stringify(range)}`,
+      `"${packageName}": ${JSON.stringify(newRange)}`,
+    );
+  }
+
+  if (oldRange === null) {
+    throw new Error(`${packageJsonPath}: no dependency entry found for ${packageName}`);
+  }
+
+  if (opts.minimumReleaseAge !== undefined) {
+    const versions = await new NpmSource().listVersions(packageName);
+    assertReleaseOldEnough(versions, newVersion, opts.minimumReleaseAge);
+  }
+
+  if (rewritten !== content) {
+    const transaction = new FileTransaction();
+    transaction.stage(packageJsonPath, rewritten);
+    await transaction.commit(opts.signal);
+  }
+
+  return { oldVersion: oldRange };
+}
+
+export class NpmUpdater implements Updater {
+  readonly fileType = "npm" as const;
+
+  matches(path: string): boolean {
+    return basename(path) === "package.json";
+  }
+
+  apply(
+    file: string,
+    packageName: string,
+    newVersion: string,
+    opts: UpdateOptions,
+  ): Promise<UpdateOutcome> {
+    return applyNpmUpdate(file, packageName, newVersion, {
+      ...(opts.minimumReleaseAge !== undefined
+        ? { minimumReleaseAge: opts.minimumReleaseAge }
+        : {}),
+      ...(opts.signal !== undefined ? { signal: opts.signal } : {}),
+    });
+  }
+}
@@ -0,0 +1,5 @@
+/** The version baked into releases; `self-update` compares against it. */
+export const version = "0.1.0";
+
+/** GitHub repo that publishes treeupdt's release binaries. */
+export const selfRepo = "treeupdt/treeupdt";
@@ -0,0 +1,34 @@
+/**
+ * Mapping between tag names and versions for packages with unusual release
+ * formats (date-based tags, `release/2024.05`, product prefixes).
+ * `extract-version` goes tag -> version, `tag-template` goes version -> tag.
+ */
+
+/**
+ * Apply an `extract-version` regex to a tag. The pattern's first capture
+ * group is the version; tags that don't match are not releases and yield
+ * null. An invalid pattern or one without a capture group is an error.
+ */
+export function extractVersion(pattern: string, tag: string): string | null {
+  const regex = new RegExp(pattern);
+  const match = tag.match(regex);
+  if (match === null) return null;
+  const captured = match[1];
+  if (captured === undefined) {
+    throw new Error(`extract-version pattern has no capture group: ${pattern}`);
+  }
+  return captured;
+}
+
+/** Throws when `pattern` is not a usable `extract-version` regex. */
+export function validateExtractVersion(pattern: string): void {
+  const regex = new RegExp(pattern);
+  if (new RegExp(`${regex.source}|`).exec("")?.length !== 2) {
+    throw new Error(`extract-version pattern has no capture group: ${pattern}`);
+  }
+}
+
+/** Render a `tag-template` like `release/{{version}}` for a version. */
+export function renderTag(template: string, version: string): string {
+  return template.replaceAll("{{version}}", version);
+}
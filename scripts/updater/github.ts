@@ -16,7 +16,7 @@ function parseGithubReleaseResponse(data: unknown, context: string): GithubRelea
   return { tagName };
 }
 
-function buildGithubHeaders(token: string | undefined): HeadersInit {
+export function buildGithubHeaders(token: string | undefined): HeadersInit {
   const headers: Record<string, string> = {
     "Accept": "application/vnd.github+json",
     "User-Agent": "agentNix-updater",
@@ -27,7 +27,7 @@ function buildGithubHeaders(token: string | undefined): HeadersInit {
   return headers;
 }
 
-function resolveGithubToken(explicitToken?: string): string | undefined {
+export function resolveGithubToken(explicitToken?: string): string | undefined {
   return explicitToken ?? Deno.env.get("GH_TOKEN") ?? Deno.env.get("GITHUB_TOKEN") ?? undefined;
 }
 